@@ -0,0 +1,282 @@
+//! Builds an AccessKit tree from UI nodes.
+//!
+//! The roles are inferred from the elements: a [`Text`] becomes a label and an [`ImageNode`] an image.
+//! Widgets insert an [`AccessibilityNode`] with their own role, a button-like role
+//! without a label is named from the text of its descendants.
+
+use accesskit::{Action, Role};
+use bevy_a11y::{AccessibilityNode, AccessibilitySystems, ActionRequest};
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::{Camera, CameraUpdateSystems, visibility::InheritedVisibility};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    entity::{Entity, EntityHashSet},
+    hierarchy::{ChildOf, Children},
+    lifecycle::RemovedComponents,
+    message::MessageReader,
+    query::{Changed, With, Without},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Local, Query, Res, ResMut},
+};
+use bevy_input_focus::{InputFocus, tab_navigation::TabIndex};
+use bevy_transform::{TransformSystems, components::GlobalTransform};
+
+use crate::{
+    UiSystems,
+    computed::ComputedNode,
+    elements::{image::ImageNode, node::Node, text::Text},
+    stack::UiStackMap,
+    utils::project_node_rect,
+};
+
+/// A plugin that synchronizes the UI nodes with the accessibility tree.
+pub struct UiAccessibilityPlugin;
+
+impl Plugin for UiAccessibilityPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_message::<ActionRequest>()
+            .add_systems(
+                PostUpdate,
+                (
+                    (label_changed, image_changed),
+                    name_from_contents,
+                    calc_bounds
+                        .after(TransformSystems::Propagate)
+                        .after(CameraUpdateSystems)
+                        .after(UiSystems::Stack),
+                )
+                    .chain()
+                    .before(AccessibilitySystems::Update),
+            )
+            .add_systems(PostUpdate, focus_requested.before(UiSystems::Prepare));
+
+        #[cfg(feature = "picking")]
+        app.add_systems(
+            PostUpdate,
+            interaction_changed.before(AccessibilitySystems::Update),
+        );
+    }
+}
+
+/// The roles which are named from the contents, if no label is given.
+fn is_named_from_contents(role: Role) -> bool {
+    matches!(
+        role,
+        Role::Button
+            | Role::CheckBox
+            | Role::RadioButton
+            | Role::Switch
+            | Role::Link
+            | Role::Tab
+            | Role::MenuItem
+            | Role::MenuItemCheckBox
+            | Role::MenuItemRadio
+            | Role::ListBoxOption
+            | Role::TreeItem
+            | Role::Cell
+            | Role::ColumnHeader
+            | Role::RowHeader
+            | Role::Tooltip
+    )
+}
+
+fn label_changed(
+    mut commands: Commands,
+    mut query: Query<(Entity, &Text, Option<&mut AccessibilityNode>), Changed<Text>>,
+) {
+    for (entity, text, accessible) in query.iter_mut() {
+        match accessible {
+            Some(mut accessible) => {
+                if accessible.role() == Role::Label {
+                    accessible.set_value(text.0.as_str());
+                }
+            }
+            None => {
+                let mut node = accesskit::Node::new(Role::Label);
+                node.set_value(text.0.as_str());
+                commands
+                    .entity(entity)
+                    .try_insert(AccessibilityNode::from(node));
+            }
+        }
+    }
+}
+
+type ImageChangedFilter = (
+    Changed<ImageNode>,
+    Without<Text>,
+    Without<AccessibilityNode>,
+);
+
+fn image_changed(mut commands: Commands, query: Query<Entity, ImageChangedFilter>) {
+    for entity in query.iter() {
+        commands
+            .entity(entity)
+            .try_insert(AccessibilityNode::from(accesskit::Node::new(Role::Image)));
+    }
+}
+
+/// The nodes whose labels are derived, and the nodes to update.
+#[derive(Default)]
+struct DerivedLabels {
+    derived: EntityHashSet,
+    dirty: EntityHashSet,
+}
+
+/// Updates the labels derived from the text of the descendants.
+fn name_from_contents(
+    mut query: Query<(Entity, &mut AccessibilityNode), With<Node>>,
+    changed_text_query: Query<Entity, Changed<Text>>,
+    text_query: Query<&Text>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    mut removed: RemovedComponents<AccessibilityNode>,
+    mut labels: Local<DerivedLabels>,
+) {
+    let DerivedLabels { derived, dirty } = &mut *labels;
+
+    for entity in removed.read() {
+        derived.remove(&entity);
+    }
+
+    dirty.clear();
+    dirty.extend(
+        query
+            .iter_mut()
+            .filter_map(|(entity, accessible)| accessible.is_added().then_some(entity)),
+    );
+    for entity in changed_text_query.iter() {
+        dirty.extend(parent_query.iter_ancestors(entity));
+    }
+
+    for &entity in dirty.iter() {
+        let Ok((_, mut accessible)) = query.get_mut(entity) else {
+            continue;
+        };
+
+        if !is_named_from_contents(accessible.role()) {
+            continue;
+        }
+        // The label is given explicitly.
+        if accessible.label().is_some() && !derived.contains(&entity) {
+            continue;
+        }
+
+        let label = text_query
+            .iter_many(children_query.iter_descendants(entity))
+            .map(|text| text.0.trim())
+            .filter(|text| !text.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+
+        if label.is_empty() {
+            accessible.clear_label();
+            derived.remove(&entity);
+        } else if accessible.label() != Some(label.as_str()) {
+            accessible.set_label(label);
+            derived.insert(entity);
+        }
+    }
+}
+
+type BoundsData = (
+    Entity,
+    &'static mut AccessibilityNode,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+    &'static InheritedVisibility,
+    Option<&'static TabIndex>,
+);
+
+/// Projects the nodes into the screen space of their cameras, in physical pixels.
+fn calc_bounds(
+    ui_stack_map: Res<UiStackMap>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    mut query: Query<BoundsData, With<Node>>,
+) {
+    for (entity, mut accessible, computed_node, transform, inherited_visibility, tab_index) in
+        query.iter_mut()
+    {
+        let focusable = tab_index.is_some_and(|tab_index| tab_index.0 >= 0);
+        if focusable != accessible.supports_action(Action::Focus) {
+            if focusable {
+                accessible.add_action(Action::Focus);
+            } else {
+                accessible.remove_action(Action::Focus);
+            }
+        }
+
+        let bounds = ui_stack_map
+            .find_camera(entity)
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+            .filter(|_| inherited_visibility.get() && !computed_node.is_empty())
+            .and_then(|(camera, camera_transform)| {
+                let rect =
+                    project_node_rect(camera, camera_transform, transform, computed_node.size)?;
+                let scale = camera.target_scaling_factor().unwrap_or(1.0);
+                let (min, max) = (rect.min * scale, rect.max * scale);
+
+                Some(accesskit::Rect::new(
+                    min.x as f64,
+                    min.y as f64,
+                    max.x as f64,
+                    max.y as f64,
+                ))
+            });
+
+        match bounds {
+            Some(bounds) => {
+                if accessible.is_hidden() {
+                    accessible.clear_hidden();
+                }
+                if accessible.bounds() != Some(bounds) {
+                    accessible.set_bounds(bounds);
+                }
+            }
+            None => {
+                if !accessible.is_hidden() {
+                    accessible.set_hidden();
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "picking")]
+fn interaction_changed(
+    mut query: Query<
+        (&crate::interaction::Interaction, &mut AccessibilityNode),
+        Changed<crate::interaction::Interaction>,
+    >,
+) {
+    for (interaction, mut accessible) in query.iter_mut() {
+        if interaction.disabled != accessible.is_disabled() {
+            if interaction.disabled {
+                accessible.set_disabled();
+            } else {
+                accessible.clear_disabled();
+            }
+        }
+    }
+}
+
+/// Moves the focus when an assistive technology requests it.
+fn focus_requested(
+    mut requests: MessageReader<ActionRequest>,
+    mut input_focus: ResMut<InputFocus>,
+    query: Query<(), (With<Node>, With<TabIndex>)>,
+) {
+    for request in requests.read() {
+        if request.action != Action::Focus {
+            continue;
+        }
+
+        let Some(entity) = Entity::try_from_bits(request.target.0) else {
+            continue;
+        };
+
+        if query.contains(entity) {
+            input_focus.set(entity);
+        }
+    }
+}
@@ -58,6 +58,9 @@ pub struct ComputedNode {
     /// The size of the content box of the node.
     pub content_box_size: Vec2,
 
+    /// The opacity of the node, multiplied by the opacities of its ancestors.
+    pub opacity: f32,
+
     /// This is the affine of the node relative to its parent.
     /// Stores it for inversion.
     pub affine: Affine3A,
@@ -84,6 +87,7 @@ impl ComputedNode {
         corner_radii: [0.0; 4],
         outline: [0.0; 2],
         content_box_size: Vec2::ZERO,
+        opacity: 1.0,
 
         affine: Affine3A::IDENTITY,
     };
@@ -92,6 +96,12 @@ impl ComputedNode {
         self.size.cmpeq(Vec2::ZERO).any()
     }
 
+    /// Returns the maximum scroll offset of the overflowing content.
+    #[inline]
+    pub fn max_scroll(&self) -> Vec2 {
+        (self.content_size - self.size + self.scrollbar_size).max(Vec2::ZERO)
+    }
+
     pub fn set_corner_radii(&mut self, corner_radii: Corners<f32>) {
         use crate::geometry::Resolve;
 
@@ -115,6 +125,13 @@ impl ComputedNode {
         self.outline = [width, offset].resolve(self.size.x);
     }
 
+    /// Multiplies the alpha of the color by the opacity of the node.
+    #[inline]
+    pub fn with_opacity<T: Alpha>(&self, color: T) -> T {
+        let alpha = color.alpha();
+        color.with_alpha(alpha * self.opacity)
+    }
+
     pub fn outline_size(&self) -> Vec2 {
         let distance = self.outline[0] + self.outline[1];
         self.size + distance * 2.0
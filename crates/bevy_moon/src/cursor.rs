@@ -0,0 +1,182 @@
+//! Window cursor icons of hovered UI nodes.
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_camera::{Camera, NormalizedRenderTarget};
+use bevy_ecs::{
+    component::Component,
+    entity::{ContainsEntity, Entity},
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Local, Query, Res, SystemParam},
+};
+use bevy_picking::{
+    backend::HitData,
+    hover::HoverMap,
+    pointer::{PointerId, PointerLocation},
+};
+use bevy_window::{CursorIcon, SystemCursorIcon};
+
+use crate::{
+    UiSystems,
+    computed::ComputedNode,
+    interaction::DisabledQuery,
+    resize::{ResizeEdges, ResizeState},
+};
+
+/// The cursor icon of the window while the node is hovered.
+///
+/// Nodes without it use the cursor of their nearest ancestor.
+#[derive(Component, Clone, Debug, PartialEq)]
+pub struct HoverCursor(pub CursorIcon);
+
+impl HoverCursor {
+    #[inline]
+    pub fn new(icon: impl Into<CursorIcon>) -> Self {
+        Self(icon.into())
+    }
+}
+
+impl From<SystemCursorIcon> for HoverCursor {
+    fn from(icon: SystemCursorIcon) -> Self {
+        Self(icon.into())
+    }
+}
+
+/// A plugin that sets the window [`CursorIcon`] from the hovered UI nodes.
+pub struct HoverCursorPlugin;
+
+impl Plugin for HoverCursorPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PreUpdate, hover_cursor_system.after(UiSystems::Focus));
+    }
+}
+
+/// The cursor icon of the window under the mouse.
+#[derive(SystemParam)]
+pub struct MouseCursor<'w, 's> {
+    pointers: Query<'w, 's, (&'static PointerId, &'static PointerLocation)>,
+    window_query: Query<'w, 's, Option<&'static CursorIcon>>,
+    // The cursor of the window before it was overridden, restored when no node sets one.
+    overridden: Local<'s, Option<(Entity, Option<CursorIcon>)>>,
+    commands: Commands<'w, 's>,
+}
+
+impl MouseCursor<'_, '_> {
+    /// Returns the window under the mouse.
+    pub fn window(&self) -> Option<Entity> {
+        self.pointers.iter().find_map(|(id, location)| {
+            if !id.is_mouse() {
+                return None;
+            }
+            match location.location.as_ref()?.target {
+                NormalizedRenderTarget::Window(window) => Some(window.entity()),
+                _ => None,
+            }
+        })
+    }
+
+    /// Overrides the cursor of the window, until [`restore`](Self::restore) is called.
+    pub fn set(&mut self, window: Entity, icon: CursorIcon) {
+        let Ok(current) = self.window_query.get(window).map(|icon| icon.cloned()) else {
+            return;
+        };
+
+        if self
+            .overridden
+            .as_ref()
+            .is_none_or(|(entity, _)| *entity != window)
+        {
+            self.restore();
+            *self.overridden = Some((window, current.clone()));
+        }
+
+        if current.as_ref() != Some(&icon) {
+            self.commands.entity(window).try_insert(icon);
+        }
+    }
+
+    /// Restores the cursor of the overridden window.
+    pub fn restore(&mut self) {
+        let Some((window, previous)) = self.overridden.take() else {
+            return;
+        };
+
+        let Ok(mut window) = self.commands.get_entity(window) else {
+            return;
+        };
+
+        match previous {
+            Some(previous) => window.try_insert(previous),
+            None => window.try_remove::<CursorIcon>(),
+        };
+    }
+}
+
+pub fn hover_cursor_system(
+    hover_map: Res<HoverMap>,
+    camera_query: Query<&Camera>,
+    node_query: Query<&ComputedNode>,
+    cursor_query: Query<&HoverCursor>,
+    resize_query: Query<&ResizeState>,
+    disabled_query: DisabledQuery,
+    mut mouse_cursor: MouseCursor,
+) {
+    // A running resize keeps its cursor, even if the pointer is outside.
+    let resizing = resize_query
+        .iter()
+        .find(|state| state.is_resizing())
+        .map(|state| state.active);
+
+    // The top-most hovered node of the mouse, the nearest hit of the camera with the highest order.
+    let order = |hit: &HitData| {
+        camera_query
+            .get(hit.camera)
+            .map_or(isize::MIN, |camera| camera.order)
+    };
+    let top = hover_map
+        .get(&PointerId::Mouse)
+        .into_iter()
+        .flatten()
+        .filter(|(entity, _)| node_query.contains(**entity))
+        .max_by(|(_, a), (_, b)| order(a).cmp(&order(b)).then(b.depth.total_cmp(&a.depth)))
+        .map(|(&entity, _)| entity);
+
+    let icon = resizing.and_then(resize_cursor).or_else(|| {
+        let top = top?;
+        // Disabled nodes use the cursor of their nearest enabled ancestor.
+        core::iter::once(top)
+            .chain(disabled_query.parent_query.iter_ancestors(top))
+            .filter(|&entity| !disabled_query.is_disabled(entity))
+            .find_map(|entity| {
+                if let Ok(state) = resize_query.get(entity)
+                    && let Some(icon) = resize_cursor(state.hovered)
+                {
+                    return Some(icon);
+                }
+                cursor_query.get(entity).ok().map(|cursor| cursor.0.clone())
+            })
+    });
+
+    match (icon, mouse_cursor.window()) {
+        (Some(icon), Some(window)) => mouse_cursor.set(window, icon),
+        _ => mouse_cursor.restore(),
+    }
+}
+
+fn resize_cursor(edges: ResizeEdges) -> Option<CursorIcon> {
+    let horizontal = edges.intersection(ResizeEdges::HORIZONTAL);
+    let vertical = edges.intersection(ResizeEdges::VERTICAL);
+
+    let icon = match (horizontal, vertical) {
+        (ResizeEdges::LEFT, ResizeEdges::TOP) | (ResizeEdges::RIGHT, ResizeEdges::BOTTOM) => {
+            SystemCursorIcon::NwseResize
+        }
+        (ResizeEdges::RIGHT, ResizeEdges::TOP) | (ResizeEdges::LEFT, ResizeEdges::BOTTOM) => {
+            SystemCursorIcon::NeswResize
+        }
+        (horizontal, _) if !horizontal.is_empty() => SystemCursorIcon::EwResize,
+        (_, vertical) if !vertical.is_empty() => SystemCursorIcon::NsResize,
+        _ => return None,
+    };
+
+    Some(icon.into())
+}
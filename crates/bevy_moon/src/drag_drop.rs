@@ -0,0 +1,502 @@
+//! Drag-and-drop between UI nodes.
+
+use core::{any::Any, fmt};
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    observer::On,
+    query::With,
+    reflect::ReflectComponent,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut, SystemParam},
+    world::World,
+};
+use bevy_math::{Vec2, Vec3};
+use bevy_picking::{
+    Pickable,
+    events::{Drag, DragEnd, DragStart, Pointer},
+    hover::HoverMap,
+    pointer::{PointerButton, PointerId},
+};
+use bevy_platform::sync::Arc;
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_transform::components::{GlobalTransform, Transform};
+use taffy::Dimension;
+
+use crate::{
+    UiSystems, computed::ComputedNode, elements::node::Node, interaction::DisabledQuery,
+    picking::pointer_local_point, resize::ResizeState, style::Style,
+};
+
+/// The distance of the ghost in front of the dragged node, keeps it on top.
+const GHOST_DEPTH_OFFSET: f32 = 0.1;
+
+/// Makes a UI node draggable with the primary pointer button.
+///
+/// Attach a [`DragPayload`] to carry data to the [`DropTarget`]s.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Node)]
+pub struct Draggable {
+    /// Spawns a translucent copy of the node which follows the pointer.
+    pub ghost: bool,
+    /// The opacity of the ghost.
+    pub ghost_opacity: f32,
+}
+
+impl Default for Draggable {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Draggable {
+    pub const DEFAULT: Self = Self {
+        ghost: true,
+        ghost_opacity: 0.6,
+    };
+
+    /// Drags without a ghost, e.g. for connecting ports.
+    pub const WITHOUT_GHOST: Self = Self {
+        ghost: false,
+        ..Self::DEFAULT
+    };
+}
+
+/// The data carried by a drag, of any type.
+#[derive(Component, Clone)]
+pub struct DragPayload(Arc<dyn Any + Send + Sync>);
+
+impl DragPayload {
+    #[inline]
+    pub fn new<T: Any + Send + Sync>(value: T) -> Self {
+        Self(Arc::new(value))
+    }
+
+    #[inline]
+    pub fn is<T: Any>(&self) -> bool {
+        self.0.is::<T>()
+    }
+
+    #[inline]
+    pub fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.0.downcast_ref::<T>()
+    }
+}
+
+impl fmt::Debug for DragPayload {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("DragPayload").finish_non_exhaustive()
+    }
+}
+
+/// Accepts the drops of [`Draggable`] nodes.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Node)]
+pub struct DropTarget;
+
+/// The translucent copy of a dragged node.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct DragGhost;
+
+/// Triggered on a [`DropTarget`] when a drag enters it.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct DropEnter {
+    pub entity: Entity,
+    /// The dragged node.
+    pub source: Entity,
+    /// The pointer position in the local coordinates of the target, from its center.
+    pub position: Vec2,
+    pub payload: Option<DragPayload>,
+}
+
+/// Triggered on a [`DropTarget`] when a drag moves over it.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct DropOver {
+    pub entity: Entity,
+    /// The dragged node.
+    pub source: Entity,
+    /// The pointer position in the local coordinates of the target, from its center.
+    pub position: Vec2,
+    pub payload: Option<DragPayload>,
+}
+
+/// Triggered on a [`DropTarget`] when a drag leaves it without dropping.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct DropLeave {
+    pub entity: Entity,
+    /// The dragged node.
+    pub source: Entity,
+}
+
+/// Triggered on a [`DropTarget`] when a drag is released over it.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct Dropped {
+    pub entity: Entity,
+    /// The dragged node.
+    pub source: Entity,
+    /// The pointer position in the local coordinates of the target, from its center.
+    pub position: Vec2,
+    pub payload: Option<DragPayload>,
+}
+
+/// The drag in progress.
+#[derive(Resource, Clone, Debug, Default)]
+pub struct ActiveDrag(Option<DragState>);
+
+impl ActiveDrag {
+    /// Returns the dragged node.
+    #[inline]
+    pub fn source(&self) -> Option<Entity> {
+        self.0.as_ref().map(|state| state.source)
+    }
+
+    /// Returns the drop target under the pointer.
+    #[inline]
+    pub fn target(&self) -> Option<Entity> {
+        self.0.as_ref().and_then(|state| state.target)
+    }
+
+    /// Returns the payload of the drag.
+    #[inline]
+    pub fn payload(&self) -> Option<&DragPayload> {
+        self.0.as_ref().and_then(|state| state.payload.as_ref())
+    }
+}
+
+#[derive(Clone, Debug)]
+struct DragState {
+    source: Entity,
+    pointer_id: PointerId,
+    camera: Entity,
+    payload: Option<DragPayload>,
+    ghost: Option<Entity>,
+    /// The transform of the source when the drag started, the ghost moves on its plane.
+    plane: GlobalTransform,
+    /// The grabbed point in the local coordinates of the source.
+    grab: Vec2,
+    target: Option<Entity>,
+}
+
+/// A plugin that adds drag-and-drop for [`Draggable`] nodes.
+pub struct DragDropPlugin;
+
+impl Plugin for DragDropPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ActiveDrag>()
+            .add_observer(on_drag_start)
+            .add_observer(on_drag)
+            .add_observer(on_drag_end)
+            .add_systems(PostUpdate, cancel_drag_system.before(UiSystems::Prepare));
+    }
+}
+
+fn on_drag_start(
+    mut event: On<Pointer<DragStart>>,
+    draggable_query: Query<(
+        &Draggable,
+        &GlobalTransform,
+        &ComputedNode,
+        Option<&DragPayload>,
+    )>,
+    resize_query: Query<&ResizeState>,
+    disabled_query: DisabledQuery,
+    mut active: ResMut<ActiveDrag>,
+    mut commands: Commands,
+) {
+    let entity = event.entity;
+    let Ok((draggable, transform, computed_node, payload)) = draggable_query.get(entity) else {
+        return;
+    };
+
+    if disabled_query.is_disabled(entity) {
+        return;
+    }
+
+    // Grabbing a resize edge resizes instead.
+    if resize_query
+        .get(entity)
+        .is_ok_and(|state| !state.hovered.is_empty())
+    {
+        return;
+    }
+
+    event.propagate(false);
+
+    if event.button != PointerButton::Primary || active.0.is_some() {
+        return;
+    }
+
+    let grab = event
+        .hit
+        .position
+        .map(|position| {
+            transform
+                .affine()
+                .inverse()
+                .transform_point3(position)
+                .truncate()
+        })
+        .unwrap_or_default();
+
+    let ghost = draggable.ghost.then(|| {
+        spawn_ghost(
+            &mut commands,
+            entity,
+            *transform,
+            computed_node.size,
+            draggable.ghost_opacity,
+        )
+    });
+
+    active.0 = Some(DragState {
+        source: entity,
+        pointer_id: event.pointer_id,
+        camera: event.hit.camera,
+        payload: payload.cloned(),
+        ghost,
+        plane: *transform,
+        grab,
+        target: None,
+    });
+}
+
+/// Spawns a detached copy of the node and its descendants, which ignores picking.
+fn spawn_ghost(
+    commands: &mut Commands,
+    entity: Entity,
+    transform: GlobalTransform,
+    size: Vec2,
+    opacity: f32,
+) -> Entity {
+    let ghost = commands
+        .entity(entity)
+        .clone_and_spawn_with_opt_out(|builder| {
+            builder
+                .linked_cloning(true)
+                .deny::<(Draggable, DragPayload, DropTarget)>();
+        })
+        .id();
+
+    commands.queue(move |world: &mut World| {
+        let Ok(mut ghost_entity) = world.get_entity_mut(ghost) else {
+            return;
+        };
+
+        let mut ghost_transform = transform.compute_transform();
+        ghost_transform.translation = transform.transform_point(Vec3::Z * GHOST_DEPTH_OFFSET);
+
+        ghost_entity
+            .remove::<ChildOf>()
+            .insert((DragGhost, ghost_transform));
+
+        // Detached from the layout of the parent, keeps the computed size.
+        if let Some(mut style) = ghost_entity.get_mut::<Style>() {
+            style.opacity *= opacity;
+
+            let inner = style.get_mut();
+            inner.size = taffy::Size {
+                width: Dimension::length(size.x),
+                height: Dimension::length(size.y),
+            };
+            inner.margin = taffy::Rect::zero();
+            inner.inset = taffy::Rect::auto();
+        }
+
+        let mut stack = vec![ghost];
+        while let Some(entity) = stack.pop() {
+            let Ok(mut entity_mut) = world.get_entity_mut(entity) else {
+                continue;
+            };
+            entity_mut.insert(Pickable::IGNORE);
+            if let Some(children) = entity_mut.get::<Children>() {
+                stack.extend(children.iter());
+            }
+        }
+    });
+
+    ghost
+}
+
+/// Finds the [`DropTarget`]s under the pointers.
+#[derive(SystemParam)]
+struct DropTargets<'w, 's> {
+    hover_map: Res<'w, HoverMap>,
+    disabled_query: DisabledQuery<'w, 's>,
+    target_query: Query<'w, 's, &'static GlobalTransform, With<DropTarget>>,
+}
+
+impl DropTargets<'_, '_> {
+    /// The nearest enabled drop target under the pointer, skips the source and its descendants.
+    fn find(&self, pointer_id: &PointerId, source: Entity) -> Option<Entity> {
+        let hits = self.hover_map.get(pointer_id)?;
+        let mut hits = hits.iter().collect::<Vec<_>>();
+        hits.sort_by(|a, b| a.1.depth.total_cmp(&b.1.depth));
+
+        hits.into_iter().find_map(|(&entity, _)| {
+            let mut path = core::iter::once(entity)
+                .chain(self.disabled_query.parent_query.iter_ancestors(entity))
+                .collect::<Vec<_>>();
+            if let Some(index) = path.iter().position(|&e| e == source) {
+                path.drain(..=index);
+            }
+            path.into_iter()
+                .find(|&e| self.target_query.contains(e) && !self.disabled_query.is_disabled(e))
+        })
+    }
+}
+
+fn on_drag(
+    event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    drop_targets: DropTargets,
+    mut ghost_query: Query<&mut Transform, With<DragGhost>>,
+    mut active: ResMut<ActiveDrag>,
+    mut commands: Commands,
+) {
+    let Some(state) = active
+        .0
+        .as_mut()
+        .filter(|state| state.source == event.entity && state.pointer_id == event.pointer_id)
+    else {
+        return;
+    };
+
+    let pointer_position = event.pointer_location.position;
+
+    // Moves the ghost on the plane of the source.
+    if let Some(mut ghost_transform) = state
+        .ghost
+        .and_then(|ghost| ghost_query.get_mut(ghost).ok())
+        && let Some(local_point) =
+            pointer_local_point(&cameras, state.camera, pointer_position, &state.plane)
+    {
+        let offset = (local_point - state.grab).extend(GHOST_DEPTH_OFFSET);
+        ghost_transform.translation = state.plane.transform_point(offset);
+    }
+
+    let target = drop_targets.find(&state.pointer_id, state.source);
+
+    let position = |target: Entity| {
+        drop_targets
+            .target_query
+            .get(target)
+            .ok()
+            .and_then(|transform| {
+                pointer_local_point(&cameras, state.camera, pointer_position, transform)
+            })
+            .unwrap_or_default()
+    };
+
+    if state.target != target {
+        if let Some(previous) = state.target {
+            commands.trigger(DropLeave {
+                entity: previous,
+                source: state.source,
+            });
+        }
+        if let Some(target) = target {
+            commands.trigger(DropEnter {
+                entity: target,
+                source: state.source,
+                position: position(target),
+                payload: state.payload.clone(),
+            });
+        }
+        state.target = target;
+    }
+
+    if let Some(target) = target {
+        commands.trigger(DropOver {
+            entity: target,
+            source: state.source,
+            position: position(target),
+            payload: state.payload.clone(),
+        });
+    }
+}
+
+fn on_drag_end(
+    event: On<Pointer<DragEnd>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    target_query: Query<&GlobalTransform, With<DropTarget>>,
+    mut active: ResMut<ActiveDrag>,
+    mut commands: Commands,
+) {
+    if active
+        .0
+        .as_ref()
+        .is_none_or(|state| state.source != event.entity || state.pointer_id != event.pointer_id)
+    {
+        return;
+    }
+
+    let Some(state) = active.0.take() else {
+        return;
+    };
+
+    if let Some(ghost) = state.ghost {
+        commands.entity(ghost).try_despawn();
+    }
+
+    let Some(target) = state.target else {
+        return;
+    };
+
+    let position = target_query
+        .get(target)
+        .ok()
+        .and_then(|transform| {
+            pointer_local_point(
+                &cameras,
+                state.camera,
+                event.pointer_location.position,
+                transform,
+            )
+        })
+        .unwrap_or_default();
+
+    commands.trigger(Dropped {
+        entity: target,
+        source: state.source,
+        position,
+        payload: state.payload,
+    });
+}
+
+/// Cancels the drag when its source is despawned, no longer [`Draggable`] or disabled.
+fn cancel_drag_system(
+    draggable_query: Query<(), With<Draggable>>,
+    disabled_query: DisabledQuery,
+    mut active: ResMut<ActiveDrag>,
+    mut commands: Commands,
+) {
+    if active.0.as_ref().is_none_or(|state| {
+        draggable_query.contains(state.source) && !disabled_query.is_disabled(state.source)
+    }) {
+        return;
+    }
+
+    let Some(state) = active.0.take() else {
+        return;
+    };
+
+    if let Some(ghost) = state.ghost {
+        commands.entity(ghost).try_despawn();
+    }
+
+    if let Some(target) = state.target {
+        commands.trigger(DropLeave {
+            entity: target,
+            source: state.source,
+        });
+    }
+}
@@ -1,4 +1,5 @@
 use bevy_ecs::{component::Component, prelude::ReflectComponent};
+use bevy_math::Vec2;
 use bevy_reflect::{Reflect, prelude::ReflectDefault};
 use bevy_transform::components::Transform;
 
@@ -20,3 +21,31 @@ pub struct Node;
 /// the node will not be clipped regardless of its ancestors' `Overflow` setting.
 #[derive(Component)]
 pub struct OverrideClip;
+
+/// Root UI node entities with this component are stacked above the other roots of their camera,
+/// regardless of their z, likes tooltips and popups.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct TopLayer;
+
+/// The stacking order of a UI node relative to its siblings, higher values are stacked on top.
+///
+/// Nodes with the same `ZIndex` are ordered by their z.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct ZIndex(pub i32);
+
+/// The stacking order of a UI node relative to the whole stack of its camera.
+///
+/// The node and its descendants are stacked like a root, while still laid out and clipped by its parent.
+/// It's stacked in the layer of its root, e.g. in the top layer inside a modal.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct GlobalZIndex(pub i32);
+
+/// The scroll offset of a UI node in logical pixels, its children are moved up and left by it.
+///
+/// Scrolled by the mouse wheel when the overflow of the node is `Scroll`.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct ScrollPosition(pub Vec2);
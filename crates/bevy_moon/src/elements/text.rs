@@ -1,13 +1,16 @@
-use bevy_asset::Assets;
+use bevy_asset::{Assets, Handle};
 use bevy_camera::Camera;
+use bevy_color::Color;
 use bevy_derive::{Deref, DerefMut};
 use bevy_ecs::{
     change_detection::DetectChanges,
     component::Component,
     entity::Entity,
-    query::With,
+    hierarchy::ChildOf,
+    lifecycle::RemovedComponents,
+    query::{Changed, With},
     reflect::ReflectComponent,
-    system::{Query, Res, ResMut},
+    system::{Query, Res, ResMut, SystemParam},
     world::{Mut, Ref},
 };
 use bevy_image::{Image, TextureAtlasLayout};
@@ -16,8 +19,11 @@ use bevy_reflect::{Reflect, prelude::ReflectDefault};
 use bevy_text::{
     ComputedTextBlock, CosmicFontSystem, Font, FontAtlasSet, FontHinting, LineBreak, LineHeight,
     SwashCache, TextBounds, TextColor, TextError, TextFont, TextLayout, TextLayoutInfo,
-    TextMeasureInfo, TextPipeline, TextReader, TextRoot, TextSpanAccess,
+    TextMeasureInfo, TextPipeline, TextReader, TextRoot, TextSpanAccess, load_font_to_fontdb,
 };
+use cosmic_text::SwashContent;
+use fixedbitset::FixedBitSet;
+use smallvec::SmallVec;
 
 use crate::{
     computed::{ComputedNode, ComputedTargetInfo},
@@ -60,6 +66,7 @@ impl TextNodeFlags {
     TextColor,
     LineHeight,
     TextNodeFlags,
+    ColorGlyphs,
     ContentSize,
     // Disable hinting.
     // UI text is normally pixel-aligned, but with hinting enabled sometimes the text bounds are miscalculated slightly.
@@ -97,6 +104,38 @@ impl From<String> for Text {
     }
 }
 
+/// Fallback fonts of the [`TextFont`] of a [`Text`] or a text span, in order of preference,
+/// used for the characters missing from the font, like emoji and other scripts.
+///
+/// Each character is shaped with the first font which covers it, the spans without fallbacks
+/// use the fallbacks of their [`Text`].
+#[derive(Component, Debug, Default, Clone, Deref, DerefMut, Reflect, PartialEq)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct TextFontFallbacks(pub Vec<Handle<Font>>);
+
+impl TextFontFallbacks {
+    /// Makes a new fallback chain, in order of preference.
+    pub fn new(fonts: impl IntoIterator<Item = Handle<Font>>) -> Self {
+        Self(fonts.into_iter().collect())
+    }
+}
+
+/// The loaded fonts and the fallbacks of the texts.
+#[derive(SystemParam)]
+pub struct TextFonts<'w, 's> {
+    pub fonts: Res<'w, Assets<Font>>,
+    pub fallbacks_query: Query<'w, 's, &'static TextFontFallbacks>,
+}
+
+/// A part of a text span, shaped with one font of its fallback chain.
+type TextRun<'a> = (Entity, usize, &'a str, TextFont, Color, LineHeight);
+
+/// The glyphs of [`TextLayoutInfo`] which are rasterized with their own colors, likes emoji.
+///
+/// They are rendered without the [`TextColor`] tint.
+#[derive(Component, Debug, Default, Clone, Deref)]
+pub struct ColorGlyphs(FixedBitSet);
+
 /// Text measurement for UI layout. See [`NodeMeasure`].
 pub struct TextMeasure {
     // All values are scaled in text measure info.
@@ -182,8 +221,136 @@ impl Measure for TextMeasure {
     }
 }
 
+/// Schedules a new measure of the texts whose [`TextFontFallbacks`] changed, or of their spans.
+pub fn detect_text_fallbacks_changes_system(
+    fallbacks_query: Query<Entity, Changed<TextFontFallbacks>>,
+    mut removed_fallbacks: RemovedComponents<TextFontFallbacks>,
+    parent_query: Query<&ChildOf>,
+    mut text_query: Query<&mut TextNodeFlags, With<Node>>,
+) {
+    for entity in fallbacks_query.iter().chain(removed_fallbacks.read()) {
+        let Some(root) = core::iter::once(entity)
+            .chain(parent_query.iter_ancestors(entity))
+            .find(|&e| text_query.contains(e))
+        else {
+            continue;
+        };
+
+        if let Ok(mut text_flags) = text_query.get_mut(root) {
+            text_flags.needs_measure_fn = true;
+        }
+    }
+}
+
+/// Splits the spans into runs of the first font which covers their characters, from the
+/// [`TextFont`] then its [`TextFontFallbacks`].
+///
+/// The characters which no font covers are kept in the [`TextFont`], and fall back to the fonts
+/// chosen by cosmic-text.
+fn split_fallback_runs<'a>(
+    root: Entity,
+    spans: impl Iterator<Item = (Entity, usize, &'a str, &'a TextFont, Color, LineHeight)>,
+    text_fonts: &TextFonts,
+    text_pipeline: &mut TextPipeline,
+    font_system: &mut CosmicFontSystem,
+    runs: &mut Vec<TextRun<'a>>,
+) -> Result<(), TextError> {
+    for (entity, depth, span, text_font, color, line_height) in spans {
+        let fallbacks = text_fonts
+            .fallbacks_query
+            .get(entity)
+            .or_else(|_| text_fonts.fallbacks_query.get(root))
+            .ok()
+            .filter(|fallbacks| !fallbacks.is_empty());
+
+        let Some(fallbacks) = fallbacks.filter(|_| !span.is_empty()) else {
+            runs.push((entity, depth, span, text_font.clone(), color, line_height));
+            continue;
+        };
+
+        let mut chain = SmallVec::<[_; 4]>::new();
+        for font in core::iter::once(&text_font.font).chain(fallbacks.iter()) {
+            // Try again next frame
+            if !text_fonts.fonts.contains(font) {
+                return Err(TextError::NoSuchFont);
+            }
+
+            load_font_to_fontdb(
+                &TextFont::from(font.clone()),
+                font_system,
+                &mut text_pipeline.map_handle_to_font_id,
+                &text_fonts.fonts,
+            );
+
+            if let Some(face) = text_pipeline
+                .get_font_id(font.id())
+                .and_then(|font_id| font_system.get_font(font_id, text_font.weight.into()))
+            {
+                chain.push((font, face));
+            }
+        }
+
+        let charmaps = chain
+            .iter()
+            .map(|(_, face)| face.as_swash().charmap())
+            .collect::<SmallVec<[_; 4]>>();
+
+        let run = |font: &Handle<Font>, text: &'a str| {
+            let text_font = TextFont {
+                font: font.clone(),
+                ..text_font.clone()
+            };
+            (entity, depth, text, text_font, color, line_height)
+        };
+
+        let mut start = 0;
+        let mut current = 0;
+        let mut joined = false;
+        for (index, c) in span.char_indices() {
+            // Keeps the characters of an emoji sequence or with combining marks together.
+            let chosen = if index > 0 && (joined || extends_cluster(c)) {
+                current
+            } else {
+                charmaps
+                    .iter()
+                    .position(|charmap| charmap.map(c) != 0)
+                    .unwrap_or_default()
+            };
+            joined = c == '\u{200D}';
+
+            if chosen != current && index > start {
+                runs.push(run(chain[current].0, &span[start..index]));
+                start = index;
+            }
+            current = chosen;
+        }
+
+        match chain.get(current) {
+            Some((font, _)) => runs.push(run(font, &span[start..])),
+            None => runs.push((entity, depth, span, text_font.clone(), color, line_height)),
+        }
+    }
+
+    Ok(())
+}
+
+/// Returns whether the character is part of the cluster before it, like a zero width joiner,
+/// a variation selector, an emoji modifier or a combining mark.
+const fn extends_cluster(c: char) -> bool {
+    matches!(
+        c,
+        '\u{0300}'..='\u{036F}'
+            | '\u{200D}'
+            | '\u{20E3}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{E0100}'..='\u{E01EF}'
+    )
+}
+
 pub fn measure_text_system(
-    fonts: Res<Assets<Font>>,
+    text_fonts: TextFonts,
     ui_stack_map: Res<UiStackMap>,
     camera_query: Query<Ref<ComputedTargetInfo>, With<Camera>>,
     mut text_query: Query<
@@ -232,10 +399,29 @@ pub fn measure_text_system(
             continue;
         }
 
-        match text_pipeline.create_text_measure(
+        let mut runs = Vec::new();
+        if split_fallback_runs(
             entity,
-            &fonts,
             text_reader.iter(entity),
+            &text_fonts,
+            &mut text_pipeline,
+            &mut font_system,
+            &mut runs,
+        )
+        .is_err()
+        {
+            // Try again next frame
+            text_flags.needs_measure_fn = true;
+            continue;
+        }
+
+        match text_pipeline.create_text_measure(
+            entity,
+            &text_fonts.fonts,
+            runs.iter()
+                .map(|(entity, depth, text, text_font, color, line_height)| {
+                    (*entity, *depth, *text, text_font, *color, *line_height)
+                }),
             applied_scale_factor as f64,
             &text_layout,
             &mut computed_text_block,
@@ -284,6 +470,7 @@ pub fn text_system(
         &mut TextLayoutInfo,
         &mut ComputedTextBlock,
         &mut TextNodeFlags,
+        &mut ColorGlyphs,
     )>,
     text_font_query: Query<&TextFont>,
     ui_stack_map: Res<UiStackMap>,
@@ -301,6 +488,7 @@ pub fn text_system(
         mut text_layout_info,
         mut computed_text_block,
         mut text_flags,
+        mut color_glyphs,
     ) in text_query.iter_mut()
     {
         let target_info = ui_stack_map
@@ -372,12 +560,48 @@ pub fn text_system(
                 text_layout_info.scale_factor = applied_scale_factor;
                 text_layout_info.size *= applied_scale_factor.recip();
 
+                update_color_glyphs(
+                    &mut color_glyphs.0,
+                    &computed_text_block,
+                    &mut font_system,
+                    &mut swash_cache,
+                );
+
                 text_flags.needs_recompute = false;
             }
         }
     }
 }
 
+/// Marks the colored glyphs, in the same order as [`TextLayoutInfo::glyphs`].
+fn update_color_glyphs(
+    color_glyphs: &mut FixedBitSet,
+    computed_text_block: &ComputedTextBlock,
+    font_system: &mut CosmicFontSystem,
+    swash_cache: &mut SwashCache,
+) {
+    color_glyphs.clear();
+
+    for (i, glyph) in computed_text_block
+        .buffer()
+        .layout_runs()
+        .flat_map(|run| run.glyphs.iter())
+        .enumerate()
+    {
+        // Rasterized once, then cached by the swash cache.
+        let is_color = swash_cache
+            .0
+            .get_image(&mut font_system.0, glyph.physical((0., 0.), 1.).cache_key)
+            .as_ref()
+            .is_some_and(|image| image.content == SwashContent::Color);
+
+        if is_color {
+            color_glyphs.grow(i + 1);
+            color_glyphs.insert(i);
+        }
+    }
+}
+
 /// Extracts the scale factor, zoom factor, and change flag from the target info.
 fn extract_values(target_info: Option<Ref<ComputedTargetInfo>>) -> (f32, f32, bool) {
     match target_info {
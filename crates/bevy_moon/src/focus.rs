@@ -0,0 +1,246 @@
+//! Keyboard and gamepad focus navigation between UI nodes.
+//!
+//! Focusable nodes are marked with a [`TabIndex`] inside a [`TabGroup`].
+//! `Tab` and `Shift+Tab` cycle through them in the tab order,
+//! the arrow keys and the gamepad D-pad move the focus to the nearest node in that direction.
+//! Both keep the focus inside a modal [`TabGroup`].
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_camera::{Camera, visibility::InheritedVisibility};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    query::With,
+    reflect::ReflectComponent,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res, ResMut, SystemParam},
+};
+use bevy_input::{
+    ButtonInput, InputSystems,
+    gamepad::{Gamepad, GamepadButton},
+    keyboard::KeyCode,
+};
+use bevy_input_focus::{
+    InputDispatchPlugin, InputFocus, InputFocusVisible,
+    directional_navigation::{
+        AutoNavigationConfig, DirectionalNavigationMap, DirectionalNavigationPlugin, FocusableArea,
+    },
+    navigator::find_best_candidate,
+    tab_navigation::TabNavigationPlugin,
+};
+use bevy_math::CompassOctant;
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_transform::components::GlobalTransform;
+use taffy::LengthPercentage;
+
+pub use bevy_input_focus::tab_navigation::{TabGroup, TabIndex};
+
+use crate::{
+    UiSystems, computed::ComputedNode, elements::node::Node, stack::UiStackMap, style::Outline,
+    utils::project_node_rect,
+};
+
+/// The outline drawn around the focused node when the focus is visible,
+/// e.g. after a keyboard navigation.
+///
+/// Set to `None` to disable it.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct FocusOutline(pub Option<Outline>);
+
+// SAFETY: The taffy lengths of the `Outline` are only thread unsafe with a `calc` value,
+// which moon does not implement, the same as for `Style`.
+unsafe impl Sync for FocusOutline {}
+// SAFETY: See the `Sync` impl.
+unsafe impl Send for FocusOutline {}
+
+impl Default for FocusOutline {
+    fn default() -> Self {
+        Self(Some(Outline {
+            color: Color::srgb(0.3, 0.6, 1.0),
+            width: LengthPercentage::length(2.0),
+            offset: LengthPercentage::length(2.0),
+        }))
+    }
+}
+
+/// Keeps the focus on the node when the arrow keys or the D-pad are pressed,
+/// for the widgets which handle them themselves, e.g. sliders.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct CaptureArrowKeys;
+
+/// A plugin that handles the focus navigation of UI nodes.
+pub struct UiFocusPlugin;
+
+impl Plugin for UiFocusPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<InputDispatchPlugin>() {
+            app.add_plugins(InputDispatchPlugin);
+        }
+        if !app.is_plugin_added::<TabNavigationPlugin>() {
+            app.add_plugins(TabNavigationPlugin);
+        }
+        if !app.is_plugin_added::<DirectionalNavigationPlugin>() {
+            app.add_plugins(DirectionalNavigationPlugin);
+        }
+
+        app.init_resource::<FocusOutline>().add_systems(
+            PreUpdate,
+            ui_directional_navigation_system
+                .after(InputSystems)
+                .before(UiSystems::Focus),
+        );
+    }
+}
+
+/// The arrow keys and the gamepad D-pad.
+#[derive(SystemParam)]
+pub struct NavigationInput<'w, 's> {
+    keyboard: Option<Res<'w, ButtonInput<KeyCode>>>,
+    gamepads: Query<'w, 's, &'static Gamepad>,
+}
+
+impl NavigationInput<'_, '_> {
+    /// Returns the direction just pressed on the keyboard or a gamepad.
+    pub fn direction(&self) -> Option<CompassOctant> {
+        self.keyboard
+            .as_deref()
+            .and_then(keyboard_direction)
+            .or_else(|| self.gamepads.iter().find_map(gamepad_direction))
+    }
+}
+
+type FocusableNodeData = (
+    Entity,
+    &'static TabIndex,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+    &'static InheritedVisibility,
+);
+
+/// The focusable nodes, projected into the viewport of their camera.
+#[derive(SystemParam)]
+pub struct FocusableNodes<'w, 's> {
+    ui_stack_map: Res<'w, UiStackMap>,
+    camera_query: Query<'w, 's, (&'static Camera, &'static GlobalTransform)>,
+    node_query: Query<'w, 's, FocusableNodeData, With<Node>>,
+    parent_query: Query<'w, 's, &'static ChildOf>,
+    group_query: Query<'w, 's, &'static TabGroup>,
+}
+
+impl FocusableNodes<'_, '_> {
+    /// Returns the focusable areas the focus can move to from the focused node,
+    /// rendered by the same camera and inside the same modal tab group.
+    pub fn candidates(&self, focused: Entity) -> Option<Vec<FocusableArea>> {
+        let camera_entity = self.ui_stack_map.find_camera(focused)?;
+        let (camera, camera_transform) = self.camera_query.get(camera_entity).ok()?;
+
+        // A modal tab group keeps the focus inside it, e.g. a modal dialog.
+        let modal_group = self.parent_query.iter_ancestors(focused).find(|&ancestor| {
+            self.group_query
+                .get(ancestor)
+                .is_ok_and(|tab_group| tab_group.modal)
+        });
+
+        let candidates = self
+            .node_query
+            .iter()
+            .filter(
+                |(entity, tab_index, computed_node, _, inherited_visibility)| {
+                    tab_index.0 >= 0
+                        && inherited_visibility.get()
+                        && !computed_node.is_empty()
+                        && self.ui_stack_map.find_camera(*entity) == Some(camera_entity)
+                        && modal_group.is_none_or(|group| {
+                            self.parent_query
+                                .iter_ancestors(*entity)
+                                .any(|ancestor| ancestor == group)
+                        })
+                },
+            )
+            .filter_map(|(entity, _, computed_node, transform, _)| {
+                let rect =
+                    project_node_rect(camera, camera_transform, transform, computed_node.size)?;
+                Some(FocusableArea {
+                    entity,
+                    position: rect.center(),
+                    size: rect.size(),
+                })
+            })
+            .collect();
+
+        Some(candidates)
+    }
+}
+
+/// Moves the focus with the arrow keys or the gamepad D-pad.
+///
+/// The edges of [`DirectionalNavigationMap`] take precedence,
+/// otherwise the nearest focusable node rendered by the same camera is chosen,
+/// compared by their rects projected into the viewport.
+pub fn ui_directional_navigation_system(
+    navigation_input: NavigationInput,
+    navigation_map: Res<DirectionalNavigationMap>,
+    config: Res<AutoNavigationConfig>,
+    mut input_focus: ResMut<InputFocus>,
+    mut input_focus_visible: ResMut<InputFocusVisible>,
+    focusable_nodes: FocusableNodes,
+    capture_query: Query<(), With<CaptureArrowKeys>>,
+) {
+    let Some(direction) = navigation_input.direction() else {
+        return;
+    };
+
+    let Some(focused) = input_focus.get() else {
+        return;
+    };
+
+    if capture_query.contains(focused) {
+        return;
+    }
+
+    let next = match navigation_map.get_neighbor(focused, direction) {
+        Some(next) => Some(next),
+        None => {
+            let Some(candidates) = focusable_nodes.candidates(focused) else {
+                return;
+            };
+
+            let Some(origin) = candidates.iter().find(|area| area.entity == focused) else {
+                return;
+            };
+
+            find_best_candidate(origin, direction, &candidates, &config)
+        }
+    };
+
+    if let Some(next) = next {
+        input_focus.set(next);
+        input_focus_visible.0 = true;
+    }
+}
+
+fn keyboard_direction(keyboard: &ButtonInput<KeyCode>) -> Option<CompassOctant> {
+    [
+        (KeyCode::ArrowUp, CompassOctant::North),
+        (KeyCode::ArrowDown, CompassOctant::South),
+        (KeyCode::ArrowLeft, CompassOctant::West),
+        (KeyCode::ArrowRight, CompassOctant::East),
+    ]
+    .into_iter()
+    .find_map(|(key, direction)| keyboard.just_pressed(key).then_some(direction))
+}
+
+fn gamepad_direction(gamepad: &Gamepad) -> Option<CompassOctant> {
+    [
+        (GamepadButton::DPadUp, CompassOctant::North),
+        (GamepadButton::DPadDown, CompassOctant::South),
+        (GamepadButton::DPadLeft, CompassOctant::West),
+        (GamepadButton::DPadRight, CompassOctant::East),
+    ]
+    .into_iter()
+    .find_map(|(button, direction)| gamepad.just_pressed(button).then_some(direction))
+}
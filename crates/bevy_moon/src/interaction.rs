@@ -0,0 +1,158 @@
+//! Interaction states of UI nodes, maintained from picking.
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::{Entity, EntityHashSet},
+    hierarchy::ChildOf,
+    message::MessageReader,
+    query::With,
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Local, Query, Res, SystemParam},
+};
+use bevy_input_focus::InputFocus;
+use bevy_picking::{
+    PickingSystems,
+    events::{Pointer, Press},
+    hover::HoverMap,
+    pointer::{PointerButton, PointerPress},
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+
+use crate::{UiSystems, elements::node::Node};
+
+/// The interaction state of a UI node.
+///
+/// A node is hovered when it or one of its descendants is under a pointer,
+/// and it stays pressed until the primary button is released.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Node)]
+pub struct Interaction {
+    pub hovered: bool,
+    pub pressed: bool,
+    pub focused: bool,
+    pub disabled: bool,
+}
+
+impl Interaction {
+    pub const DEFAULT: Self = Self {
+        hovered: false,
+        pressed: false,
+        focused: false,
+        disabled: false,
+    };
+
+    pub const DISABLED: Self = Self {
+        disabled: true,
+        ..Self::DEFAULT
+    };
+
+    /// Returns `true` if the node is hovered or pressed.
+    #[inline]
+    pub const fn is_active(&self) -> bool {
+        self.hovered || self.pressed
+    }
+}
+
+/// Disables the interaction of a UI node and its descendants.
+///
+/// Picking events are still triggered, and a disabled node still blocks the nodes behind it.
+/// Drag and drop, resizing, text selection and hover cursors ignore disabled nodes,
+/// widgets should check [`Interaction::disabled`].
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct Disabled;
+
+/// Finds the nodes which are [`Disabled`], or inside a disabled ancestor.
+#[derive(SystemParam)]
+pub struct DisabledQuery<'w, 's> {
+    /// The parents of the nodes, also used to walk the ancestors of a node.
+    pub parent_query: Query<'w, 's, &'static ChildOf>,
+    disabled_query: Query<'w, 's, (), With<Disabled>>,
+}
+
+impl DisabledQuery<'_, '_> {
+    /// Returns `true` if the node or one of its ancestors is [`Disabled`].
+    pub fn is_disabled(&self, entity: Entity) -> bool {
+        self.disabled_query.contains(entity)
+            || self
+                .parent_query
+                .iter_ancestors(entity)
+                .any(|ancestor| self.disabled_query.contains(ancestor))
+    }
+}
+
+/// Marks a checkable node as checked, e.g. a checkbox or a selected radio button.
+///
+/// Activates the `checked` variant of [`StyleStates`](crate::states::StyleStates).
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct Checked;
+
+/// A plugin that maintains the [`Interaction`] of UI nodes.
+pub struct UiInteractionPlugin;
+
+impl Plugin for UiInteractionPlugin {
+    fn build(&self, app: &mut App) {
+        app.configure_sets(PreUpdate, UiSystems::Focus.after(PickingSystems::Hover))
+            .add_systems(PreUpdate, ui_interaction_system.in_set(UiSystems::Focus));
+    }
+}
+
+/// The hovered and pressed nodes, with their ancestors.
+#[derive(Default)]
+pub struct InteractedNodes {
+    hovered: EntityHashSet,
+    pressed: EntityHashSet,
+}
+
+pub fn ui_interaction_system(
+    hover_map: Res<HoverMap>,
+    input_focus: Option<Res<InputFocus>>,
+    pointers: Query<&PointerPress>,
+    disabled_query: DisabledQuery,
+    mut interaction_query: Query<(Entity, &mut Interaction)>,
+    mut press_reader: MessageReader<Pointer<Press>>,
+    mut nodes: Local<InteractedNodes>,
+) {
+    let InteractedNodes { hovered, pressed } = &mut *nodes;
+    let parent_query = &disabled_query.parent_query;
+
+    hovered.clear();
+    for &entity in hover_map.values().flat_map(|entities| entities.keys()) {
+        hovered.insert(entity);
+        hovered.extend(parent_query.iter_ancestors(entity));
+    }
+
+    for press in press_reader.read() {
+        if press.button != PointerButton::Primary {
+            continue;
+        }
+        pressed.insert(press.entity);
+        pressed.extend(parent_query.iter_ancestors(press.entity));
+    }
+
+    if !pointers.iter().any(PointerPress::is_primary_pressed) {
+        pressed.clear();
+    }
+
+    let focused = input_focus.and_then(|input_focus| input_focus.get());
+
+    for (entity, mut interaction) in interaction_query.iter_mut() {
+        let new_interaction = if disabled_query.is_disabled(entity) {
+            Interaction::DISABLED
+        } else {
+            Interaction {
+                hovered: hovered.contains(&entity),
+                pressed: pressed.contains(&entity),
+                focused: focused == Some(entity),
+                disabled: false,
+            }
+        };
+
+        interaction.set_if_neq(new_interaction);
+    }
+}
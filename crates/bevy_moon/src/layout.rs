@@ -10,12 +10,12 @@ use bevy_ecs::{
     error::Result,
     hierarchy::Children,
     lifecycle::RemovedComponents,
-    query::{Added, Changed, With},
+    query::{Added, Changed, Has, With, Without},
     resource::Resource,
     system::{Commands, Local, Query, Res, ResMut},
     world::Ref,
 };
-use bevy_math::{UVec2, Vec2};
+use bevy_math::{UVec2, Vec2, Vec3};
 use bevy_platform::collections::hash_map::Entry;
 use bevy_text::{ComputedTextBlock, CosmicFontSystem};
 use bevy_transform::components::Transform;
@@ -24,11 +24,17 @@ use stacksafe::stacksafe;
 
 use crate::{
     computed::{ComputedNode, ComputedTargetInfo},
-    elements::{node::Node, text::TextMeasure},
+    elements::{
+        node::{Node, ScrollPosition},
+        text::TextMeasure,
+    },
     geometry::VEC2_FLIP_Y,
     measure::{ContentSize, Measure, MeasureArgs, NodeContext},
+    overlay::Overlay,
+    screen_space::ScreenSpace,
     stack::UiStackMap,
     style::Style,
+    zoom_invariant::ZoomInvariant,
 };
 
 #[derive(Debug)]
@@ -237,7 +243,7 @@ impl UiLayoutEngine {
         &mut self,
         root_node_entity: Entity,
         scale_factor: f32,
-        physical_size: UVec2,
+        available_size: Vec2,
         text_block_query: &'a mut Query<&mut ComputedTextBlock>,
         font_system: &'a mut CosmicFontSystem,
     ) {
@@ -249,11 +255,11 @@ impl UiLayoutEngine {
             node_id
         });
 
-        let physical_size = physical_size.as_vec2() * scale_factor;
+        let available_size = available_size * scale_factor;
 
         let available_space = taffy::Size {
-            width: taffy::AvailableSpace::Definite(physical_size.x),
-            height: taffy::AvailableSpace::Definite(physical_size.y),
+            width: taffy::AvailableSpace::Definite(available_size.x),
+            height: taffy::AvailableSpace::Definite(available_size.y),
         };
 
         self.tree
@@ -389,9 +395,12 @@ pub fn ui_target_info_system(
         };
 
         if let Some(mut target_info) = target_info {
-            if target_info.zoom_factor != zoom_factor {
-                target_info.zoom_factor = zoom_factor;
-            }
+            // The viewport is resized with the window.
+            target_info.set_if_neq(ComputedTargetInfo {
+                scale_factor,
+                physical_size,
+                zoom_factor,
+            });
         } else {
             commands.entity(camera_entity).insert(ComputedTargetInfo {
                 scale_factor,
@@ -402,9 +411,14 @@ pub fn ui_target_info_system(
     }
 }
 
+type LayoutNodeData = (Entity, Ref<'static, Style>, Option<&'static Children>);
+
 pub fn ui_layout_system(
     camera_query: Query<&ComputedTargetInfo, With<Camera>>,
-    node_query: Query<(Entity, Ref<Style>, Option<&Children>), With<Node>>,
+    root_node_query: Query<LayoutNodeData, With<Node>>,
+    // Overlays are laid out as roots, outside of their parents.
+    node_query: Query<LayoutNodeData, (With<Node>, Without<Overlay>)>,
+    screen_space_query: Query<(), With<ScreenSpace>>,
     ui_stack_map: Res<UiStackMap>,
     mut ui_layout_engine: ResMut<UiLayoutEngine>,
     mut layouts: Local<SmallVec<[taffy::NodeId; 8]>>,
@@ -414,7 +428,11 @@ pub fn ui_layout_system(
     _added_node_query: Query<(), Added<Node>>,
     _changed_children_query: Query<Entity, (Changed<Children>, With<Node>)>,
 
-    mut update_node_query: Query<(&mut Transform, &mut ComputedNode), With<Node>>,
+    mut update_node_query: Query<
+        (&mut Transform, &mut ComputedNode, Has<ZoomInvariant>),
+        With<Node>,
+    >,
+    scroll_query: Query<&ScrollPosition>,
     mut content_size_query: Query<Option<&mut ContentSize>>,
     mut text_block_query: Query<&mut ComputedTextBlock>,
     mut font_system: ResMut<CosmicFontSystem>,
@@ -427,7 +445,7 @@ pub fn ui_layout_system(
 
         let &ComputedTargetInfo { scale_factor, .. } = targt_info;
 
-        for node in node_query.iter_many(&ui_stack.roots) {
+        for node in root_node_query.iter_many(&ui_stack.roots) {
             update_ui_layout_recursive(
                 &node_query,
                 &mut content_size_query,
@@ -450,7 +468,7 @@ pub fn ui_layout_system(
     ui_layout_engine.remove_nodes(
         removed_nodes
             .read()
-            .filter(|&entity| !node_query.contains(entity)),
+            .filter(|&entity| !root_node_query.contains(entity)),
     );
 
     // Computes ui layout by UI root entity.
@@ -465,11 +483,18 @@ pub fn ui_layout_system(
             ..
         } = targt_info;
 
-        for node in node_query.iter_many(&ui_stack.roots) {
+        for node in root_node_query.iter_many(&ui_stack.roots) {
+            // Screen space roots are laid out in the logical pixels of the viewport.
+            let available_size = if screen_space_query.contains(node.0) {
+                physical_size.as_vec2() / scale_factor
+            } else {
+                physical_size.as_vec2()
+            };
+
             ui_layout_engine.compute_layout(
                 node.0,
                 scale_factor,
-                physical_size,
+                available_size,
                 &mut text_block_query,
                 &mut font_system,
             );
@@ -477,6 +502,7 @@ pub fn ui_layout_system(
             update_ui_geometry_recursive(
                 &node_query,
                 &mut update_node_query,
+                &scroll_query,
                 &mut text_block_query,
                 &mut font_system,
                 &mut ui_layout_engine,
@@ -493,7 +519,7 @@ pub fn ui_layout_system(
 }
 
 fn update_ui_layout_recursive(
-    node_query: &Query<(Entity, Ref<Style>, Option<&Children>), With<Node>>,
+    node_query: &Query<LayoutNodeData, (With<Node>, Without<Overlay>)>,
     content_size_query: &mut Query<Option<&mut ContentSize>>,
     ui_layout_engine: &mut UiLayoutEngine,
     layouts: &mut SmallVec<[taffy::NodeId; 8]>,
@@ -552,16 +578,20 @@ fn update_ui_layout_recursive(
 }
 
 fn update_ui_geometry_recursive(
-    node_query: &Query<(Entity, Ref<Style>, Option<&Children>), With<Node>>,
-    update_node_query: &mut Query<(&mut Transform, &mut ComputedNode), With<Node>>,
+    node_query: &Query<LayoutNodeData, (With<Node>, Without<Overlay>)>,
+    update_node_query: &mut Query<
+        (&mut Transform, &mut ComputedNode, Has<ZoomInvariant>),
+        With<Node>,
+    >,
+    scroll_query: &Query<&ScrollPosition>,
     text_block_query: &mut Query<&mut ComputedTextBlock>,
     font_system: &mut CosmicFontSystem,
     ui_layout_engine: &mut UiLayoutEngine,
-    mut maybe_inherited_node: Option<(Transform, ComputedNode)>,
+    mut maybe_inherited_node: Option<(Transform, ComputedNode, Vec2)>,
     (entity, style, children): (Entity, Ref<Style>, Option<&Children>),
     scale_factor: f32,
 ) {
-    let (Ok(layout), Ok((mut transform, mut computed_node))) = (
+    let (Ok(layout), Ok((mut transform, mut computed_node, zoom_invariant))) = (
         ui_layout_engine.get_layout(entity, scale_factor),
         update_node_query.get_mut(entity),
     ) else {
@@ -575,6 +605,8 @@ fn update_ui_geometry_recursive(
 
         bypass_computed_node.set_layout(layout);
         bypass_computed_node.set_corner_radii(style.corner_radii);
+        bypass_computed_node.opacity = style.opacity.clamp(0.0, 1.0)
+            * maybe_inherited_node.map_or(1.0, |(_, parent, _)| parent.opacity);
 
         if let Some(outline) = style.outline {
             bypass_computed_node.set_outline(outline);
@@ -585,16 +617,25 @@ fn update_ui_geometry_recursive(
         }
     }
 
-    if let Some((_parent_transform, parent_computed_node)) = maybe_inherited_node {
-        // @TODO(fundon): scrolling
-        let local_center =
-            computed_node.location + 0.5 * (computed_node.size - parent_computed_node.size);
+    if let Some((_parent_transform, parent_computed_node, parent_scroll)) = maybe_inherited_node {
+        // The children of a scrolled node are moved by its scroll offset.
+        let local_center = computed_node.location - parent_scroll
+            + 0.5 * (computed_node.size - parent_computed_node.size);
         let local_center_flipped = local_center * VEC2_FLIP_Y;
 
         let mut local_affine = computed_node.affine;
         if local_center_flipped != local_affine.translation.truncate() {
-            // extracts transform without layout translation
-            let base_affine = transform.compute_affine() * local_affine.inverse();
+            // extracts transform without layout translation,
+            // the scale of a zoom invariant node doesn't move it from its place.
+            let base_affine = if zoom_invariant {
+                Transform {
+                    scale: Vec3::ONE,
+                    ..*transform
+                }
+                .compute_affine()
+            } else {
+                transform.compute_affine()
+            } * local_affine.inverse();
 
             // updates layout translation
             local_affine.translation.x = local_center_flipped.x;
@@ -612,12 +653,16 @@ fn update_ui_geometry_recursive(
 
     if let Some(children) = children {
         // Updates its children.
-        maybe_inherited_node = Some((*transform, *computed_node));
+        let scroll = scroll_query
+            .get(entity)
+            .map_or(Vec2::ZERO, |scroll| scroll.0);
+        maybe_inherited_node = Some((*transform, *computed_node, scroll));
 
         for node in node_query.iter_many(children) {
             update_ui_geometry_recursive(
                 &node_query,
                 update_node_query,
+                scroll_query,
                 text_block_query,
                 font_system,
                 ui_layout_engine,
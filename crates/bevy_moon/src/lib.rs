@@ -1,20 +1,46 @@
+pub mod accessibility;
 pub mod elements;
 pub mod properties;
+#[cfg(feature = "picking")]
+pub mod resize;
 
 mod geometry;
 pub mod style;
 
+#[cfg(feature = "picking")]
+pub mod interaction;
 #[cfg(feature = "picking")]
 mod picking;
+#[cfg(feature = "picking")]
+pub mod selection;
+#[cfg(feature = "picking")]
+pub mod states;
+#[cfg(feature = "picking")]
+pub mod tooltip;
 
 pub mod computed;
+#[cfg(feature = "picking")]
+pub mod cursor;
+#[cfg(feature = "picking")]
+pub mod drag_drop;
+pub mod focus;
 mod layout;
 pub mod measure;
+pub mod overlay;
 pub mod render;
+pub mod screen_space;
+#[cfg(feature = "picking")]
+pub mod scroll;
 mod stack;
 mod systems;
+pub mod transition;
 mod utils;
+pub mod virtualize;
+#[cfg(feature = "picking")]
+pub mod widgets;
+pub mod zoom_invariant;
 
+use accessibility::UiAccessibilityPlugin;
 use bevy_camera::{
     CameraUpdateSystems,
     visibility::{Visibility, VisibilityClass, add_visibility_class},
@@ -23,11 +49,35 @@ use bevy_ecs::schedule::{IntoScheduleConfigs, SystemSet};
 use bevy_render::{extract_resource::ExtractResourcePlugin, sync_world::SyncToRenderWorld};
 use bevy_transform::TransformSystems;
 #[cfg(feature = "picking")]
+use cursor::HoverCursorPlugin;
+#[cfg(feature = "picking")]
+use drag_drop::DragDropPlugin;
+use focus::UiFocusPlugin;
+#[cfg(feature = "picking")]
+use interaction::UiInteractionPlugin;
+#[cfg(feature = "picking")]
 use picking::UiPickingPlugin;
+#[cfg(feature = "picking")]
+use resize::ResizePlugin;
+#[cfg(feature = "picking")]
+use scroll::UiScrollPlugin;
+#[cfg(feature = "picking")]
+use selection::TextSelectionPlugin;
+#[cfg(feature = "picking")]
+use states::StyleStatesPlugin;
+#[cfg(feature = "picking")]
+use tooltip::TooltipPlugin;
 
 use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
 
+use overlay::OverlayPlugin;
 use render::plugin::UiRenderPlugin;
+use screen_space::ScreenSpacePlugin;
+use transition::TransitionPlugin;
+use virtualize::VirtualizePlugin;
+#[cfg(feature = "picking")]
+use widgets::WidgetsPlugin;
+use zoom_invariant::ZoomInvariantPlugin;
 
 use crate::{
     elements::{image, node::Node, text},
@@ -76,7 +126,18 @@ pub struct MoonPlugin;
 impl Plugin for MoonPlugin {
     fn build(&self, app: &mut App) {
         #[cfg(feature = "picking")]
-        app.add_plugins(UiPickingPlugin);
+        app.add_plugins((
+            UiPickingPlugin,
+            UiInteractionPlugin,
+            StyleStatesPlugin,
+            UiScrollPlugin,
+            TextSelectionPlugin,
+            DragDropPlugin,
+            ResizePlugin,
+            HoverCursorPlugin,
+            TooltipPlugin,
+            WidgetsPlugin,
+        ));
 
         app.register_required_components::<Node, Visibility>()
             .register_required_components::<Node, VisibilityClass>()
@@ -135,6 +196,7 @@ impl Plugin for MoonPlugin {
             (
                 (
                     bevy_text::detect_text_needs_rerender::<text::Text>,
+                    text::detect_text_fallbacks_changes_system,
                     text::measure_text_system,
                 )
                     .chain()
@@ -169,6 +231,15 @@ impl Plugin for MoonPlugin {
             AmbiguousWithUpdateText2dLayout.ambiguous_with(bevy_sprite::update_text2d_layout),
         );
 
-        app.add_plugins(UiRenderPlugin);
+        app.add_plugins((
+            UiFocusPlugin,
+            UiAccessibilityPlugin,
+            OverlayPlugin,
+            ScreenSpacePlugin,
+            ZoomInvariantPlugin,
+            TransitionPlugin,
+            VirtualizePlugin,
+            UiRenderPlugin,
+        ));
     }
 }
@@ -0,0 +1,295 @@
+//! Overlays, UI nodes which escape the clipping and stacking of their ancestors.
+//!
+//! An [`Overlay`] is laid out, stacked and clipped as a root on the top layer of its camera,
+//! even if it is spawned as a descendant of a clipped or scrolled node.
+//! Its transform is placed next to the anchor node on every frame.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    query::With,
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res},
+};
+use bevy_math::{Rect, Vec2, primitives::InfinitePlane3d};
+use bevy_transform::{
+    TransformSystems,
+    components::{GlobalTransform, Transform},
+};
+
+use crate::{
+    UiSystems,
+    computed::ComputedNode,
+    elements::node::{Node, TopLayer},
+    stack::UiStackMap,
+    utils::project_node_rect,
+};
+
+/// Places the node next to the `anchor` node, as a root on the top layer.
+///
+/// Overlays are only rendered by `camera` if set, otherwise by the camera of the anchor.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Node, TopLayer)]
+pub struct Overlay {
+    /// The node which the overlay is placed next to.
+    pub anchor: Entity,
+    /// The camera which renders the overlay.
+    pub camera: Option<Entity>,
+    /// The preferred side of the anchor, flips to the opposite side if there is no room.
+    pub placement: OverlayPlacement,
+    /// The alignment along the side of the anchor.
+    pub align: OverlayAlign,
+    /// The distance between the anchor and the overlay.
+    pub gap: f32,
+}
+
+impl Overlay {
+    #[inline]
+    pub const fn new(anchor: Entity) -> Self {
+        Self {
+            anchor,
+            camera: None,
+            placement: OverlayPlacement::Bottom,
+            align: OverlayAlign::Center,
+            gap: 0.0,
+        }
+    }
+
+    #[inline]
+    pub const fn camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+
+    #[inline]
+    pub const fn placement(mut self, placement: OverlayPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    #[inline]
+    pub const fn align(mut self, align: OverlayAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    #[inline]
+    pub const fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverlayPlacement {
+    Top,
+    #[default]
+    Bottom,
+    Left,
+    Right,
+}
+
+impl OverlayPlacement {
+    #[inline]
+    pub const fn flip(self) -> Self {
+        match self {
+            Self::Top => Self::Bottom,
+            Self::Bottom => Self::Top,
+            Self::Left => Self::Right,
+            Self::Right => Self::Left,
+        }
+    }
+
+    #[inline]
+    pub const fn is_vertical(self) -> bool {
+        matches!(self, Self::Top | Self::Bottom)
+    }
+}
+
+/// The alignment of an [`Overlay`] along the side of its anchor.
+///
+/// `Start` is the left edge above or below the anchor, and the top edge beside it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum OverlayAlign {
+    Start,
+    #[default]
+    Center,
+    End,
+}
+
+/// A plugin that places [`Overlay`] nodes next to their anchors.
+pub struct OverlayPlugin;
+
+impl Plugin for OverlayPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            overlay_position_system
+                .after(UiSystems::Layout)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+pub fn overlay_position_system(
+    ui_stack_map: Res<UiStackMap>,
+    camera_query: Query<(&Camera, &GlobalTransform)>,
+    anchor_query: Query<(&GlobalTransform, &ComputedNode), With<Node>>,
+    parent_query: Query<&GlobalTransform>,
+    mut overlay_query: Query<(
+        &Overlay,
+        &ComputedNode,
+        &GlobalTransform,
+        &mut Transform,
+        Option<&ChildOf>,
+    )>,
+) {
+    for (overlay, computed_node, global_transform, mut transform, child_of) in
+        overlay_query.iter_mut()
+    {
+        let Ok((anchor_transform, anchor_node)) = anchor_query.get(overlay.anchor) else {
+            continue;
+        };
+        let Some(anchor_camera_entity) = ui_stack_map.find_camera(overlay.anchor) else {
+            continue;
+        };
+        let camera_entity = overlay.camera.unwrap_or(anchor_camera_entity);
+
+        let (Ok((anchor_camera, anchor_camera_transform)), Ok((camera, camera_transform))) = (
+            camera_query.get(anchor_camera_entity),
+            camera_query.get(camera_entity),
+        ) else {
+            continue;
+        };
+
+        // The rects are in the logical pixels of the render target, shared by the cameras.
+        let Some(anchor_rect) = project_node_rect(
+            anchor_camera,
+            anchor_camera_transform,
+            anchor_transform,
+            anchor_node.size,
+        ) else {
+            continue;
+        };
+        let Some(viewport) = camera.logical_viewport_rect() else {
+            continue;
+        };
+
+        // The overlay lies on the plane of the anchor if they share the camera,
+        // otherwise it faces its camera.
+        let (plane, mut new_transform, pixels_per_unit) = if camera_entity == anchor_camera_entity {
+            (
+                *anchor_transform,
+                anchor_transform.compute_transform(),
+                anchor_rect.size() / anchor_node.size,
+            )
+        } else {
+            let mut new_transform = global_transform.compute_transform();
+            new_transform.rotation = camera_transform.rotation();
+            let plane = GlobalTransform::from(new_transform);
+            let pixels_per_unit = project_node_rect(camera, camera_transform, &plane, Vec2::ONE)
+                .map(|rect| rect.size())
+                .unwrap_or(Vec2::ONE);
+            (plane, new_transform, pixels_per_unit)
+        };
+        if !pixels_per_unit.is_finite() || pixels_per_unit.cmple(Vec2::ZERO).any() {
+            continue;
+        }
+
+        let size = computed_node.size * pixels_per_unit;
+        let gap = overlay.gap * pixels_per_unit;
+
+        // Flips to the opposite side if the preferred one overflows.
+        let mut placement = overlay.placement;
+        let mut rect = place(anchor_rect, size, gap, placement, overlay.align);
+        if !contains(viewport, rect) {
+            let flipped = place(anchor_rect, size, gap, placement.flip(), overlay.align);
+            if contains(viewport, flipped) {
+                placement = placement.flip();
+                rect = flipped;
+            }
+        }
+
+        // Shifts along the side to stay inside the viewport.
+        let center = if placement.is_vertical() {
+            rect.center()
+                + Vec2::X * overflow(rect.min.x, rect.max.x, viewport.min.x, viewport.max.x)
+        } else {
+            rect.center()
+                + Vec2::Y * overflow(rect.min.y, rect.max.y, viewport.min.y, viewport.max.y)
+        };
+
+        let Some(translation) = camera
+            .viewport_to_world(camera_transform, center)
+            .ok()
+            .and_then(|ray| {
+                ray.plane_intersection_point(
+                    plane.translation(),
+                    InfinitePlane3d::new(plane.back()),
+                )
+            })
+        else {
+            continue;
+        };
+        new_transform.translation = translation;
+
+        // A parented overlay keeps its place in the hierarchy, so its transform is local.
+        if let Some(parent_transform) =
+            child_of.and_then(|child_of| parent_query.get(child_of.parent()).ok())
+        {
+            new_transform = Transform::from_matrix(
+                (parent_transform.affine().inverse() * new_transform.compute_affine()).into(),
+            );
+        }
+
+        if *transform != new_transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+/// Returns the rect next to the anchor, in viewport coordinates.
+fn place(
+    anchor: Rect,
+    size: Vec2,
+    gap: Vec2,
+    placement: OverlayPlacement,
+    align: OverlayAlign,
+) -> Rect {
+    let half_size = 0.5 * size;
+    let cross = match align {
+        OverlayAlign::Start => anchor.min + half_size,
+        OverlayAlign::Center => anchor.center(),
+        OverlayAlign::End => anchor.max - half_size,
+    };
+
+    // The viewport is y down.
+    let center = match placement {
+        OverlayPlacement::Top => Vec2::new(cross.x, anchor.min.y - gap.y - half_size.y),
+        OverlayPlacement::Bottom => Vec2::new(cross.x, anchor.max.y + gap.y + half_size.y),
+        OverlayPlacement::Left => Vec2::new(anchor.min.x - gap.x - half_size.x, cross.y),
+        OverlayPlacement::Right => Vec2::new(anchor.max.x + gap.x + half_size.x, cross.y),
+    };
+
+    Rect::from_center_size(center, size)
+}
+
+#[inline]
+fn contains(outer: Rect, inner: Rect) -> bool {
+    outer.contains(inner.min) && outer.contains(inner.max)
+}
+
+/// Returns the distance to move `min..max` into `start..end`.
+#[inline]
+fn overflow(min: f32, max: f32, start: f32, end: f32) -> f32 {
+    if max - min > end - start || min < start {
+        start - min
+    } else if max > end {
+        end - max
+    } else {
+        0.0
+    }
+}
@@ -13,7 +13,7 @@ use bevy_picking::{
 use bevy_transform::components::GlobalTransform;
 use bevy_window::PrimaryWindow;
 
-use crate::{computed::ComputedNode, elements::node::Node, stack::UiStackMap};
+use crate::{computed::ComputedNode, elements::node::Node, stack::UiStackMap, style::Style};
 
 /// A plugin that adds picking support for UI nodes.
 #[derive(Clone)]
@@ -44,6 +44,7 @@ fn ui_picking(
             &InheritedVisibility,
             Option<&Pickable>,
             &ComputedNode,
+            &Style,
         ),
         With<Node>,
     >,
@@ -98,6 +99,9 @@ fn ui_picking(
 
             let mut picks = Vec::<(Entity, HitData)>::new();
             let mut blocked = false;
+            // Keeps the hits in the stack order when they are sorted by depth,
+            // a node with a higher `ZIndex` can be farther.
+            let mut min_depth = f32::NEG_INFINITY;
 
             // From front to back
             for node in ui_stack.ranges.iter().rev().flat_map(|range| {
@@ -111,9 +115,11 @@ fn ui_picking(
                     continue;
                 };
 
+                let depth = picked.1.depth.max(min_depth);
+                min_depth = depth;
                 blocked = picked.0;
 
-                picks.push((node.0, picked.1));
+                picks.push((node.0, HitData { depth, ..picked.1 }));
             }
 
             if picks.is_empty() {
@@ -140,9 +146,10 @@ fn pick(
         &InheritedVisibility,
         Option<&Pickable>,
         &ComputedNode,
+        &Style,
     ),
 ) -> Option<(bool, HitData)> {
-    let (_entity, &transform, inherited_visibility, pickable, computed_node) = node;
+    let (_entity, &transform, inherited_visibility, pickable, computed_node, style) = node;
 
     if !inherited_visibility.get() {
         return None;
@@ -163,6 +170,14 @@ fn pick(
     }
 
     let world_point = transform.transform_point(local_point.extend(0.0));
+
+    // The clipped parts are not pickable, e.g. the scrolled out children.
+    if style
+        .clip_rect
+        .is_some_and(|clip_rect| !clip_rect.contains(world_point.truncate()))
+    {
+        return None;
+    }
     // Transform point from world to camera space to get the Z distance
     let camera_point = camera_transform
         .affine()
@@ -189,7 +204,7 @@ fn pick(
 }
 
 // Transform cursor line segment to node coordinate system.
-fn normalize_point(
+pub(crate) fn normalize_point(
     (ray_origin, ray_end): (Vec3, Vec3),
     transform: GlobalTransform,
 ) -> Option<Vec2> {
@@ -217,6 +232,19 @@ fn normalize_point(
     Some(cursor_start_node.lerp(cursor_end_node, lerp_factor).xy())
 }
 
+/// Projects a viewport position of the camera onto the plane of a node,
+/// returns the node-local point from the center of the node.
+pub(crate) fn pointer_local_point(
+    cameras: &Query<(&Camera, &GlobalTransform)>,
+    camera: Entity,
+    position: Vec2,
+    transform: &GlobalTransform,
+) -> Option<Vec2> {
+    let (camera, camera_transform) = cameras.get(camera).ok()?;
+    let ray = camera.viewport_to_world(camera_transform, position).ok()?;
+    normalize_point((ray.origin, ray.origin + *ray.direction), *transform)
+}
+
 fn contains_point(
     local_point: Vec2,
     size: Vec2,
@@ -334,7 +334,7 @@ fn extract_node_style(
 
     extracted_box_nodes.box_shadows.push(ExtractedBoxShadow {
         stack_index: index,
-        color: color.into(),
+        color: computed_node.with_opacity(color.into()),
         bounds: bounds + 6. * blur_radius,
         // clip: None,
         radius: radius.into(),
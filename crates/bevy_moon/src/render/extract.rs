@@ -10,6 +10,7 @@ use bevy_ecs::{
     system::{Commands, Query, Res, ResMut},
 };
 use bevy_image::{Image, TextureAtlasLayout};
+use bevy_input_focus::{InputFocus, InputFocusVisible};
 use bevy_math::{Affine3A, Rect, UVec2, Vec2, Vec4};
 use bevy_render::{
     Extract,
@@ -23,13 +24,19 @@ use crate::{
     elements::{
         image::{ImageNode, ImageNodeSize},
         node::Node,
-        text::Text,
+        text::{ColorGlyphs, Text},
     },
+    focus::FocusOutline,
     geometry::{VEC2_FLIP_X, VEC2_FLIP_Y},
     render::flags::{ShaderFlags, StackZOffsets},
     stack::UiStackMap,
     style::Style,
 };
+#[cfg(feature = "picking")]
+use crate::{
+    resize::{Resizable, ResizeEdges, ResizeHandleColor, ResizeState},
+    selection::{TextSelection, TextSelectionColor},
+};
 
 pub enum ExtractedUiItem {
     Node {
@@ -152,7 +159,7 @@ fn extract_node_style(
             image: AssetId::default(),
             clip,
             item: ExtractedUiItem::Node {
-                color: color.into(),
+                color: computed_node.with_opacity(color.into()),
                 size,
                 atlas_scaling: None,
                 flip_x: false,
@@ -178,7 +185,7 @@ fn extract_node_style(
             .enumerate()
         {
             border_width[i] = w;
-            border_color[i] = c.to_linear();
+            border_color[i] = computed_node.with_opacity(c.to_linear());
         }
     }
 
@@ -245,25 +252,11 @@ fn extract_node_style(
         return;
     };
 
-    let color = color.into();
-    let size = computed_node.outline_size();
-    let border_width = computed_node.outline_border_width();
-    let corner_radii = computed_node.outline_corner_radii();
-
     extracted_ui_nodes.nodes.push(ExtractedNode {
         z_order: index + StackZOffsets::Border.to_percent(),
         image: AssetId::default(),
         clip,
-        item: ExtractedUiItem::Node {
-            color,
-            size,
-            atlas_scaling: None,
-            flip_x: false,
-            flip_y: false,
-            border: border_width.into(),
-            corner_radii: corner_radii.into(),
-            flags: ShaderFlags::BORDER_ALL,
-        },
+        item: outline_item(computed_node, computed_node.with_opacity(color.into())),
         transform,
         main_entity,
         render_entity,
@@ -271,6 +264,82 @@ fn extract_node_style(
     });
 }
 
+/// The outline is drawn as a border around the node.
+fn outline_item(computed_node: &ComputedNode, color: LinearRgba) -> ExtractedUiItem {
+    ExtractedUiItem::Node {
+        color,
+        size: computed_node.outline_size(),
+        atlas_scaling: None,
+        flip_x: false,
+        flip_y: false,
+        border: computed_node.outline_border_width().into(),
+        corner_radii: computed_node.outline_corner_radii().into(),
+        flags: ShaderFlags::BORDER_ALL,
+    }
+}
+
+type FocusOutlineNodeData = (
+    RenderEntity,
+    &'static GlobalTransform,
+    &'static InheritedVisibility,
+    (&'static Style, &'static ComputedNode),
+);
+
+pub fn extract_focus_outline(
+    mut extracted_ui_nodes: ResMut<ExtractedUiNodes>,
+    input_focus: Extract<Option<Res<InputFocus>>>,
+    input_focus_visible: Extract<Option<Res<InputFocusVisible>>>,
+    focus_outline: Extract<Res<FocusOutline>>,
+    node_query: Extract<Query<FocusOutlineNodeData, With<Node>>>,
+    ui_stack_map: Extract<Res<UiStackMap>>,
+) {
+    if !input_focus_visible
+        .as_ref()
+        .is_some_and(|visible| visible.0)
+    {
+        return;
+    }
+    let Some(outline) = focus_outline.0 else {
+        return;
+    };
+    let Some(entity) = input_focus.as_ref().and_then(|focus| focus.get()) else {
+        return;
+    };
+    let Some(camera_entity) = ui_stack_map.find_camera(entity) else {
+        return;
+    };
+    let Ok((render_entity, transform, inherited_visibility, (style, computed_node))) =
+        node_query.get(entity)
+    else {
+        return;
+    };
+
+    if !inherited_visibility.get() || computed_node.is_empty() {
+        return;
+    }
+
+    let mut computed_node = *computed_node;
+    computed_node.set_outline(outline);
+
+    if computed_node.outline[0] <= 0.0 {
+        return;
+    }
+
+    extracted_ui_nodes.nodes.push(ExtractedNode {
+        z_order: computed_node.stack_index as f32 + StackZOffsets::Border.to_percent(),
+        image: AssetId::default(),
+        clip: style.clip_rect,
+        item: outline_item(
+            &computed_node,
+            computed_node.with_opacity(outline.color.into()),
+        ),
+        transform: transform.affine(),
+        main_entity: MainEntity::from(entity),
+        render_entity,
+        camera_entity,
+    });
+}
+
 pub fn extract_images(
     mut extracted_nodes: ResMut<ExtractedUiNodes>,
     texture_atlases: Extract<Res<Assets<TextureAtlasLayout>>>,
@@ -353,7 +422,7 @@ pub fn extract_images(
                 image: image.image.id(),
                 clip,
                 item: ExtractedUiItem::Node {
-                    color: image.color.into(),
+                    color: computed_node.with_opacity(image.color.into()),
                     size: rect.size(),
                     atlas_scaling,
                     flip_x: image.flip_x,
@@ -371,6 +440,205 @@ pub fn extract_images(
     }
 }
 
+#[cfg(feature = "picking")]
+type ResizableNodeData = (
+    Entity,
+    RenderEntity,
+    &'static GlobalTransform,
+    &'static InheritedVisibility,
+    &'static Resizable,
+    &'static ResizeState,
+    (&'static Style, &'static ComputedNode),
+);
+
+#[cfg(feature = "picking")]
+pub fn extract_resize_handles(
+    mut extracted_ui_nodes: ResMut<ExtractedUiNodes>,
+    handle_color: Extract<Res<ResizeHandleColor>>,
+    resizable_query: Extract<Query<ResizableNodeData, With<Node>>>,
+    ui_stack_map: Extract<Res<UiStackMap>>,
+) {
+    let color: LinearRgba = handle_color.0.into();
+    if color.is_fully_transparent() {
+        return;
+    }
+
+    for (
+        entity,
+        render_entity,
+        transform,
+        inherited_visibility,
+        resizable,
+        state,
+        (style, computed_node),
+    ) in resizable_query.iter()
+    {
+        let edges = state.hovered | state.active;
+        if edges.is_empty() {
+            continue;
+        }
+        if !inherited_visibility.get() || computed_node.is_empty() {
+            continue;
+        }
+        let Some(camera_entity) = ui_stack_map.find_camera(entity) else {
+            continue;
+        };
+
+        let index = computed_node.stack_index as f32;
+        let affine = transform.affine();
+        let half_size = 0.5 * computed_node.size;
+        let [left, bottom, right, top] = resizable.margins(computed_node);
+
+        // The grab region of each edge, from the center of the node.
+        for (edge, size, center) in [
+            (
+                ResizeEdges::LEFT,
+                Vec2::new(left, computed_node.size.y),
+                Vec2::new(0.5 * left - half_size.x, 0.0),
+            ),
+            (
+                ResizeEdges::RIGHT,
+                Vec2::new(right, computed_node.size.y),
+                Vec2::new(half_size.x - 0.5 * right, 0.0),
+            ),
+            (
+                ResizeEdges::TOP,
+                Vec2::new(computed_node.size.x, top),
+                Vec2::new(0.0, half_size.y - 0.5 * top),
+            ),
+            (
+                ResizeEdges::BOTTOM,
+                Vec2::new(computed_node.size.x, bottom),
+                Vec2::new(0.0, 0.5 * bottom - half_size.y),
+            ),
+        ] {
+            if !edges.contains(edge) || size.min_element() <= 0.0 {
+                continue;
+            }
+
+            extracted_ui_nodes.nodes.push(ExtractedNode {
+                z_order: index + StackZOffsets::ResizeHandle.to_percent(),
+                image: AssetId::default(),
+                clip: style.clip_rect,
+                item: ExtractedUiItem::Node {
+                    color: computed_node.with_opacity(color),
+                    size,
+                    atlas_scaling: None,
+                    flip_x: false,
+                    flip_y: false,
+                    border: Vec4::ZERO,
+                    corner_radii: Vec4::ZERO,
+                    flags: ShaderFlags::UNTEXTURED,
+                },
+                transform: affine * Affine3A::from_translation(center.extend(0.0)),
+                main_entity: MainEntity::from(entity),
+                render_entity,
+                camera_entity,
+            });
+        }
+    }
+}
+
+#[cfg(feature = "picking")]
+type TextSelectionNodeData = (
+    Entity,
+    RenderEntity,
+    &'static GlobalTransform,
+    &'static InheritedVisibility,
+    &'static ComputedTextBlock,
+    &'static TextSelection,
+    &'static TextLayoutInfo,
+    (&'static Style, &'static ComputedNode),
+);
+
+#[cfg(feature = "picking")]
+pub fn extract_text_selections(
+    mut extracted_ui_nodes: ResMut<ExtractedUiNodes>,
+    selection_color: Extract<Res<TextSelectionColor>>,
+    text_query: Extract<Query<TextSelectionNodeData, With<Node>>>,
+    ui_stack_map: Extract<Res<UiStackMap>>,
+) {
+    let color: LinearRgba = selection_color.0.into();
+    if color.is_fully_transparent() {
+        return;
+    }
+
+    for (&camera_entity, ui_stack) in ui_stack_map.iter() {
+        for (
+            entity,
+            render_entity,
+            transform,
+            inherited_visibility,
+            computed_block,
+            selection,
+            text_layout_info,
+            (style, computed_node),
+        ) in ui_stack
+            .ranges
+            .iter()
+            .flat_map(|range| text_query.iter_many(&ui_stack.entities[range.clone()]))
+        {
+            if !inherited_visibility.get() {
+                continue;
+            }
+            if computed_node.is_empty() {
+                continue;
+            }
+            let Some((start, end)) = selection.range() else {
+                continue;
+            };
+
+            let clip = style.clip_rect;
+            let index = computed_node.stack_index as f32;
+            let main_entity = MainEntity::from(entity);
+            let affine = transform.affine();
+
+            let inverse_scale_factor = text_layout_info.scale_factor.recip();
+            let half_size = 0.5 * computed_node.size;
+
+            for run in computed_block.buffer().layout_runs() {
+                let Some((x, width)) = run.highlight(start, end) else {
+                    continue;
+                };
+
+                // Selected line breaks still get a visible highlight.
+                let width = if width > 0.0 {
+                    width
+                } else {
+                    0.25 * run.line_height
+                };
+
+                let size = Vec2::new(width, run.line_height) * inverse_scale_factor;
+                // From the top-left of the text buffer (y down) to the center of the node (y up).
+                let center = Vec2::new(
+                    x * inverse_scale_factor + 0.5 * size.x - half_size.x,
+                    half_size.y - run.line_top * inverse_scale_factor - 0.5 * size.y,
+                );
+
+                extracted_ui_nodes.nodes.push(ExtractedNode {
+                    z_order: index + StackZOffsets::TextSelection.to_percent(),
+                    image: AssetId::default(),
+                    clip,
+                    item: ExtractedUiItem::Node {
+                        color: computed_node.with_opacity(color),
+                        size,
+                        atlas_scaling: None,
+                        flip_x: false,
+                        flip_y: false,
+                        border: Vec4::ZERO,
+                        corner_radii: Vec4::ZERO,
+                        flags: ShaderFlags::UNTEXTURED,
+                    },
+                    transform: affine * Affine3A::from_translation(center.extend(0.0)),
+                    main_entity,
+                    render_entity,
+                    camera_entity,
+                });
+            }
+        }
+    }
+}
+
 pub fn extract_texts(
     mut commands: Commands,
     mut extracted_ui_nodes: ResMut<ExtractedUiNodes>,
@@ -386,6 +654,7 @@ pub fn extract_texts(
                 &Text,
                 &TextColor,
                 &TextLayoutInfo,
+                &ColorGlyphs,
                 (&Style, &ComputedNode),
             ),
             With<Node>,
@@ -407,6 +676,7 @@ pub fn extract_texts(
             _text,
             text_color,
             text_layout_info,
+            color_glyphs,
             (style, computed_node),
         ) in ui_stack
             .ranges
@@ -467,7 +737,12 @@ pub fn extract_texts(
                     [location.glyph_index]
                     .as_rect();
                 extracted_ui_nodes.glyphs.push(ExtractedGlyph {
-                    color,
+                    // Colored glyphs keep their own colors, only the alpha is applied.
+                    color: computed_node.with_opacity(if color_glyphs.contains(i) {
+                        LinearRgba::WHITE.with_alpha(color.alpha)
+                    } else {
+                        color
+                    }),
                     rect,
                     translation: position,
                 });
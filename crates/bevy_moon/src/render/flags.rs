@@ -67,9 +67,10 @@ pub enum StackZOffsets {
     // Gradient = 2,
     // BorderGradient = 3,
     Image = 4,
-    // Material = 5,
+    TextSelection = 5,
     Text = 6,
     // TextStrikeThrough = 7,
+    ResizeHandle = 8,
 }
 
 impl StackZOffsets {
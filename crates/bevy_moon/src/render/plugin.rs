@@ -26,13 +26,15 @@ use bevy_shader::load_shader_library;
 use bevy_sprite_render::SpriteAssetEvents;
 use bevy_utils::default;
 
+#[cfg(feature = "picking")]
+use crate::render::extract::{extract_resize_handles, extract_text_selections};
 use crate::{
     geometry::VEC2_FLIP_Y,
     render::{
         box_shadow::BoxShadowPlugin,
         extract::{
-            ExtractedGlyph, ExtractedUiItem, ExtractedUiNodes, extract_images, extract_node_styles,
-            extract_texts,
+            ExtractedGlyph, ExtractedUiItem, ExtractedUiNodes, extract_focus_outline,
+            extract_images, extract_node_styles, extract_texts,
         },
         flags::ShaderFlags,
         graph::add_moon_ui_subgraph,
@@ -72,7 +74,10 @@ impl Plugin for UiRenderPlugin {
                     RenderUiSystems::ExtractCameraViews,
                     RenderUiSystems::ExtractBoxShadows,
                     RenderUiSystems::ExtractNodeStyles,
+                    RenderUiSystems::ExtractFocusOutline,
+                    RenderUiSystems::ExtractResizeHandles,
                     RenderUiSystems::ExtractImages,
+                    RenderUiSystems::ExtractTextSelections,
                     RenderUiSystems::ExtractTexts,
                 )
                     .chain(),
@@ -83,19 +88,30 @@ impl Plugin for UiRenderPlugin {
                 (
                     extract_camera_views.in_set(RenderUiSystems::ExtractCameraViews),
                     extract_node_styles.in_set(RenderUiSystems::ExtractNodeStyles),
+                    extract_focus_outline.in_set(RenderUiSystems::ExtractFocusOutline),
                     extract_images.in_set(RenderUiSystems::ExtractImages),
                     extract_texts.in_set(RenderUiSystems::ExtractTexts),
                 ),
-            )
-            .add_systems(
-                Render,
-                (
-                    queue_nodes.in_set(RenderSystems::Queue),
-                    sort_phase_system::<TransparentUi>.in_set(RenderSystems::PhaseSort),
-                    prepare_nodes.in_set(RenderSystems::PrepareBindGroups),
-                ),
             );
 
+        #[cfg(feature = "picking")]
+        render_app.add_systems(
+            ExtractSchedule,
+            (
+                extract_resize_handles.in_set(RenderUiSystems::ExtractResizeHandles),
+                extract_text_selections.in_set(RenderUiSystems::ExtractTextSelections),
+            ),
+        );
+
+        render_app.add_systems(
+            Render,
+            (
+                queue_nodes.in_set(RenderSystems::Queue),
+                sort_phase_system::<TransparentUi>.in_set(RenderSystems::PhaseSort),
+                prepare_nodes.in_set(RenderSystems::PrepareBindGroups),
+            ),
+        );
+
         add_moon_ui_subgraph(render_app);
 
         app.add_plugins(BoxShadowPlugin);
@@ -5,7 +5,10 @@ pub enum RenderUiSystems {
     ExtractCameraViews,
     ExtractBoxShadows,
     ExtractNodeStyles,
+    ExtractFocusOutline,
+    ExtractResizeHandles,
     ExtractImages,
+    ExtractTextSelections,
     ExtractTexts,
     // ExtractBackgrounds,
     // ExtractTextureSlice,
@@ -0,0 +1,368 @@
+//! Interactive resizing of UI nodes by dragging their edges and corners.
+
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_camera::Camera;
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::{Entity, EntityHashMap},
+    hierarchy::ChildOf,
+    observer::On,
+    query::Has,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Local, Query, Res},
+};
+use bevy_math::{Vec2, Vec3Swizzles};
+use bevy_picking::{
+    events::{Drag, DragEnd, DragStart, Pointer},
+    hover::HoverMap,
+    pointer::PointerButton,
+};
+use bevy_transform::components::{GlobalTransform, Transform};
+use taffy::Dimension;
+
+use crate::{
+    UiSystems, computed::ComputedNode, elements::node::Node, interaction::DisabledQuery,
+    picking::pointer_local_point, style::Style,
+};
+
+bitflags::bitflags! {
+    #[derive(Default, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    #[repr(transparent)]
+    /// The edges of a node, two adjacent edges make a corner.
+    pub struct ResizeEdges: u8 {
+        const LEFT   = 1 << 0;
+        const RIGHT  = 1 << 1;
+        const TOP    = 1 << 2;
+        const BOTTOM = 1 << 3;
+        const HORIZONTAL = Self::LEFT.bits() | Self::RIGHT.bits();
+        const VERTICAL   = Self::TOP.bits() | Self::BOTTOM.bits();
+        const ALL        = Self::HORIZONTAL.bits() | Self::VERTICAL.bits();
+    }
+}
+
+/// Makes a UI node resizable by dragging its edges and corners.
+///
+/// The edges are grabbed inside the border of the node, or within `grab_margin` if set.
+/// The new size is written into the `size` of the [`Style`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Node, ResizeState)]
+pub struct Resizable {
+    /// The edges which can be dragged.
+    pub edges: ResizeEdges,
+    /// The width of the grab region, uses the border widths if `None`.
+    pub grab_margin: Option<f32>,
+    pub min_size: Vec2,
+    pub max_size: Vec2,
+    /// Keeps the ratio of width to height.
+    pub aspect_ratio: Option<f32>,
+}
+
+impl Default for Resizable {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Resizable {
+    pub const DEFAULT: Self = Self {
+        edges: ResizeEdges::ALL,
+        grab_margin: None,
+        min_size: Vec2::ZERO,
+        max_size: Vec2::INFINITY,
+        aspect_ratio: None,
+    };
+
+    #[inline]
+    pub const fn new(edges: ResizeEdges) -> Self {
+        Self {
+            edges,
+            ..Self::DEFAULT
+        }
+    }
+
+    #[inline]
+    pub const fn grab_margin(mut self, margin: f32) -> Self {
+        self.grab_margin = Some(margin);
+        self
+    }
+
+    #[inline]
+    pub const fn min_size(mut self, size: Vec2) -> Self {
+        self.min_size = size;
+        self
+    }
+
+    #[inline]
+    pub const fn max_size(mut self, size: Vec2) -> Self {
+        self.max_size = size;
+        self
+    }
+
+    #[inline]
+    pub const fn aspect_ratio(mut self, ratio: f32) -> Self {
+        self.aspect_ratio = Some(ratio);
+        self
+    }
+
+    /// Returns the widths of the grab regions: [left, bottom, right, top].
+    pub fn margins(&self, computed_node: &ComputedNode) -> [f32; 4] {
+        match self.grab_margin {
+            Some(margin) => [margin; 4],
+            None => computed_node.border,
+        }
+    }
+
+    /// Returns the edges under the node-local point, from the center of the node.
+    pub fn hit_edges(&self, local_point: Vec2, computed_node: &ComputedNode) -> ResizeEdges {
+        let [left, bottom, right, top] = self.margins(computed_node);
+        let half_size = 0.5 * computed_node.size;
+
+        let mut edges = ResizeEdges::empty();
+        edges.set(ResizeEdges::LEFT, local_point.x < left - half_size.x);
+        edges.set(ResizeEdges::RIGHT, local_point.x > half_size.x - right);
+        edges.set(ResizeEdges::TOP, local_point.y > half_size.y - top);
+        edges.set(ResizeEdges::BOTTOM, local_point.y < bottom - half_size.y);
+
+        edges & self.edges
+    }
+
+    /// Applies the size and aspect-ratio constraints.
+    pub fn constrain(&self, size: Vec2, edges: ResizeEdges) -> Vec2 {
+        let mut size = size.max(self.min_size).min(self.max_size);
+
+        if let Some(ratio) = self.aspect_ratio.filter(|ratio| *ratio > 0.0) {
+            // Follows the dragged axis, the width wins at the corners.
+            if edges.intersects(ResizeEdges::HORIZONTAL) {
+                size.y = size.x / ratio;
+            } else {
+                size.x = size.y * ratio;
+            }
+            size = size.max(self.min_size).min(self.max_size);
+        }
+
+        size.max(Vec2::ZERO)
+    }
+}
+
+/// The hovered and dragged edges of a [`Resizable`] node.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ResizeState {
+    pub hovered: ResizeEdges,
+    pub active: ResizeEdges,
+    start: Option<ResizeStart>,
+}
+
+impl ResizeState {
+    /// Returns `true` while the node is being resized.
+    #[inline]
+    pub fn is_resizing(&self) -> bool {
+        !self.active.is_empty()
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ResizeStart {
+    camera: Entity,
+    size: Vec2,
+    /// The grabbed point in the local coordinates of the node.
+    point: Vec2,
+    plane: GlobalTransform,
+    transform: Transform,
+}
+
+/// The color of the hovered and dragged resize edges.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ResizeHandleColor(pub Color);
+
+impl Default for ResizeHandleColor {
+    fn default() -> Self {
+        Self(Color::srgba(0.3, 0.6, 1.0, 0.6))
+    }
+}
+
+/// A plugin that adds interactive resizing for [`Resizable`] nodes.
+pub struct ResizePlugin;
+
+impl Plugin for ResizePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ResizeHandleColor>()
+            .add_systems(PreUpdate, resize_hover_system.in_set(UiSystems::Focus))
+            .add_observer(on_resize_start)
+            .add_observer(on_resize)
+            .add_observer(on_resize_end);
+    }
+}
+
+pub fn resize_hover_system(
+    hover_map: Res<HoverMap>,
+    disabled_query: DisabledQuery,
+    mut resizable_query: Query<(
+        Entity,
+        &Resizable,
+        &mut ResizeState,
+        &GlobalTransform,
+        &ComputedNode,
+    )>,
+    mut hovered: Local<EntityHashMap<ResizeEdges>>,
+) {
+    hovered.clear();
+
+    for (&entity, hit) in hover_map.values().flatten() {
+        let Some(position) = hit.position else {
+            continue;
+        };
+
+        for entity in
+            core::iter::once(entity).chain(disabled_query.parent_query.iter_ancestors(entity))
+        {
+            let Ok((_, resizable, _, transform, computed_node)) = resizable_query.get(entity)
+            else {
+                continue;
+            };
+            // The edges of a disabled node are not hovered, so it's not resized.
+            if disabled_query.is_disabled(entity) {
+                continue;
+            }
+
+            let local_point = transform.affine().inverse().transform_point3(position).xy();
+            let edges = resizable.hit_edges(local_point, computed_node);
+
+            *hovered.entry(entity).or_default() |= edges;
+        }
+    }
+
+    for (entity, _, mut state, ..) in resizable_query.iter_mut() {
+        let edges = hovered.get(&entity).copied().unwrap_or_default();
+        if state.hovered != edges {
+            state.hovered = edges;
+        }
+    }
+}
+
+fn on_resize_start(
+    mut event: On<Pointer<DragStart>>,
+    mut resizable_query: Query<(
+        &mut ResizeState,
+        &GlobalTransform,
+        &Transform,
+        &ComputedNode,
+    )>,
+) {
+    let Ok((mut state, global_transform, transform, computed_node)) =
+        resizable_query.get_mut(event.entity)
+    else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary || state.hovered.is_empty() {
+        return;
+    }
+
+    let Some(position) = event.hit.position else {
+        return;
+    };
+
+    event.propagate(false);
+
+    state.active = state.hovered;
+    state.start = Some(ResizeStart {
+        camera: event.hit.camera,
+        size: computed_node.size,
+        point: global_transform
+            .affine()
+            .inverse()
+            .transform_point3(position)
+            .xy(),
+        plane: *global_transform,
+        transform: *transform,
+    });
+}
+
+fn on_resize(
+    mut event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut resizable_query: Query<(
+        &Resizable,
+        &ResizeState,
+        &mut Style,
+        &mut Transform,
+        Has<ChildOf>,
+    )>,
+) {
+    let Ok((resizable, state, mut style, mut transform, has_parent)) =
+        resizable_query.get_mut(event.entity)
+    else {
+        return;
+    };
+
+    let Some(start) = state.start.filter(|_| state.is_resizing()) else {
+        return;
+    };
+
+    event.propagate(false);
+
+    let Some(local_point) = pointer_local_point(
+        &cameras,
+        start.camera,
+        event.pointer_location.position,
+        &start.plane,
+    ) else {
+        return;
+    };
+
+    let edges = state.active;
+    let delta = local_point - start.point;
+
+    // The dragged edges move, the opposite edges stay.
+    let direction = Vec2::new(
+        if edges.contains(ResizeEdges::RIGHT) {
+            1.0
+        } else if edges.contains(ResizeEdges::LEFT) {
+            -1.0
+        } else {
+            0.0
+        },
+        if edges.contains(ResizeEdges::TOP) {
+            1.0
+        } else if edges.contains(ResizeEdges::BOTTOM) {
+            -1.0
+        } else {
+            0.0
+        },
+    );
+
+    let mut size = start.size + delta * direction;
+    if direction.x == 0.0 {
+        size.x = start.size.x;
+    }
+    if direction.y == 0.0 {
+        size.y = start.size.y;
+    }
+    let size = resizable.constrain(size, edges);
+
+    let inner = style.get_mut();
+    inner.size = taffy::Size {
+        width: Dimension::length(size.x),
+        height: Dimension::length(size.y),
+    };
+
+    // A root is centered on its transform, moves it to keep the opposite edges in place.
+    if !has_parent {
+        let offset = 0.5 * (size - start.size) * direction;
+        transform.translation = start.transform.translation
+            + start.transform.rotation * (start.transform.scale * offset.extend(0.0));
+    }
+}
+
+fn on_resize_end(event: On<Pointer<DragEnd>>, mut resizable_query: Query<&mut ResizeState>) {
+    let Ok(mut state) = resizable_query.get_mut(event.entity) else {
+        return;
+    };
+
+    if state.is_resizing() {
+        state.active = ResizeEdges::empty();
+        state.start = None;
+    }
+}
@@ -0,0 +1,206 @@
+//! Screen space roots, UI nodes which are pinned to the viewport of a camera.
+//!
+//! A [`ScreenSpace`] root is laid out in the logical pixels of the viewport, so its percentages
+//! resolve against the viewport size, and it's placed at its anchor on every frame. It stays
+//! fixed while the camera pans and zooms, and is stacked above the world space roots.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::{Camera, Projection};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    query::Without,
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res},
+};
+use bevy_math::{Vec2, Vec3, primitives::InfinitePlane3d};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_transform::{
+    TransformSystems,
+    components::{GlobalTransform, Transform},
+};
+
+use crate::{
+    UiSystems, computed::ComputedNode, elements::node::Node, stack::UiStackMap,
+    utils::project_node_rect,
+};
+
+/// Pins a root node to the viewport of its camera, at the `anchor`.
+///
+/// The node is only rendered by `camera` if set, otherwise by the rendering camera with the
+/// lowest order.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Node)]
+pub struct ScreenSpace {
+    /// The point of the viewport and of the node which are placed together.
+    pub anchor: ScreenAnchor,
+    /// The offset from the anchor in logical pixels, y down.
+    pub offset: Vec2,
+    /// The camera which renders the node.
+    pub camera: Option<Entity>,
+}
+
+impl ScreenSpace {
+    #[inline]
+    pub const fn new(anchor: ScreenAnchor) -> Self {
+        Self {
+            anchor,
+            offset: Vec2::ZERO,
+            camera: None,
+        }
+    }
+
+    #[inline]
+    pub const fn offset(mut self, offset: Vec2) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    #[inline]
+    pub const fn camera(mut self, camera: Entity) -> Self {
+        self.camera = Some(camera);
+        self
+    }
+}
+
+/// A point of a rect, e.g. the top-left corner of the viewport.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum ScreenAnchor {
+    #[default]
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl ScreenAnchor {
+    /// Returns the point as a fraction of the size of the rect, from the top-left corner.
+    #[inline]
+    pub const fn fraction(self) -> Vec2 {
+        match self {
+            Self::TopLeft => Vec2::new(0.0, 0.0),
+            Self::Top => Vec2::new(0.5, 0.0),
+            Self::TopRight => Vec2::new(1.0, 0.0),
+            Self::Left => Vec2::new(0.0, 0.5),
+            Self::Center => Vec2::new(0.5, 0.5),
+            Self::Right => Vec2::new(1.0, 0.5),
+            Self::BottomLeft => Vec2::new(0.0, 1.0),
+            Self::Bottom => Vec2::new(0.5, 1.0),
+            Self::BottomRight => Vec2::new(1.0, 1.0),
+        }
+    }
+
+    #[inline]
+    pub const fn is_top(self) -> bool {
+        matches!(self, Self::TopLeft | Self::Top | Self::TopRight)
+    }
+
+    #[inline]
+    pub const fn is_bottom(self) -> bool {
+        matches!(self, Self::BottomLeft | Self::Bottom | Self::BottomRight)
+    }
+
+    #[inline]
+    pub const fn is_left(self) -> bool {
+        matches!(self, Self::TopLeft | Self::Left | Self::BottomLeft)
+    }
+
+    #[inline]
+    pub const fn is_right(self) -> bool {
+        matches!(self, Self::TopRight | Self::Right | Self::BottomRight)
+    }
+}
+
+/// A plugin that places the [`ScreenSpace`] roots at their anchors.
+pub struct ScreenSpacePlugin;
+
+impl Plugin for ScreenSpacePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            screen_space_system
+                .after(UiSystems::Layout)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+pub fn screen_space_system(
+    ui_stack_map: Res<UiStackMap>,
+    camera_query: Query<(&Camera, &GlobalTransform, &Projection)>,
+    mut node_query: Query<(Entity, &ScreenSpace, &ComputedNode, &mut Transform), Without<ChildOf>>,
+) {
+    for (entity, screen_space, computed_node, mut transform) in node_query.iter_mut() {
+        let Some(camera_entity) = screen_space
+            .camera
+            .or_else(|| ui_stack_map.find_camera(entity))
+        else {
+            continue;
+        };
+        let Ok((camera, camera_transform, projection)) = camera_query.get(camera_entity) else {
+            continue;
+        };
+        let Some(viewport) = camera.logical_viewport_rect() else {
+            continue;
+        };
+
+        // The center of the node in the render target, the viewport is y down.
+        let fraction = screen_space.anchor.fraction();
+        let position = viewport.min
+            + fraction * viewport.size()
+            + screen_space.offset
+            + (Vec2::splat(0.5) - fraction) * computed_node.size;
+
+        let Some(new_transform) =
+            viewport_transform(camera, camera_transform, projection, position)
+        else {
+            continue;
+        };
+        if *transform != new_transform {
+            *transform = new_transform;
+        }
+    }
+}
+
+/// Places a node at a viewport position of the camera, facing the camera,
+/// with a unit of the node as a logical pixel regardless of the zoom of the camera.
+fn viewport_transform(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    projection: &Projection,
+    position: Vec2,
+) -> Option<Transform> {
+    // A plane between the near and far planes.
+    let distance = match projection {
+        Projection::Perspective(projection) => 2.0 * projection.near,
+        Projection::Orthographic(projection) => 0.5 * (projection.near + projection.far),
+        _ => return None,
+    };
+
+    let mut transform = camera_transform.compute_transform();
+    transform.translation += camera_transform.forward() * distance;
+    transform.scale = Vec3::ONE;
+
+    let plane = GlobalTransform::from(transform);
+    let pixels_per_unit = project_node_rect(camera, camera_transform, &plane, Vec2::ONE)?.size();
+    if !pixels_per_unit.is_finite() || pixels_per_unit.cmple(Vec2::ZERO).any() {
+        return None;
+    }
+
+    transform.translation = camera
+        .viewport_to_world(camera_transform, position)
+        .ok()?
+        .plane_intersection_point(plane.translation(), InfinitePlane3d::new(plane.back()))?;
+    transform.scale = pixels_per_unit.recip().extend(1.0);
+
+    Some(transform)
+}
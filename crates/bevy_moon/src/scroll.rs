@@ -0,0 +1,55 @@
+//! Scrolling UI nodes with the mouse wheel.
+
+use bevy_app::{App, Plugin};
+use bevy_ecs::{observer::On, system::Query};
+use bevy_input::mouse::MouseScrollUnit;
+use bevy_math::Vec2;
+use bevy_picking::events::{Pointer, Scroll};
+
+use crate::{computed::ComputedNode, elements::node::ScrollPosition, style::Style};
+
+/// The pixels scrolled by a line of the mouse wheel.
+pub const LINE_HEIGHT: f32 = 21.0;
+
+/// A plugin that scrolls the hovered nodes with a [`ScrollPosition`].
+pub struct UiScrollPlugin;
+
+impl Plugin for UiScrollPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_scroll);
+    }
+}
+
+/// Scrolls the node if its overflow is `Scroll` and it can move in the direction,
+/// otherwise the event bubbles up to its ancestors.
+fn on_scroll(
+    mut event: On<Pointer<Scroll>>,
+    mut node_query: Query<(&mut ScrollPosition, &ComputedNode, &Style)>,
+) {
+    let Ok((mut scroll_position, computed_node, style)) = node_query.get_mut(event.entity) else {
+        return;
+    };
+
+    let mut delta = Vec2::new(event.x, event.y);
+    if event.unit == MouseScrollUnit::Line {
+        delta *= LINE_HEIGHT;
+    }
+
+    let overflow = style.get_ref().overflow;
+    if overflow.x != taffy::Overflow::Scroll {
+        delta.x = 0.0;
+    }
+    if overflow.y != taffy::Overflow::Scroll {
+        delta.y = 0.0;
+    }
+
+    // Scrolling the wheel up moves the content down.
+    let offset = (scroll_position.0 - delta).clamp(Vec2::ZERO, computed_node.max_scroll());
+    if offset == scroll_position.0 {
+        return;
+    }
+
+    event.propagate(false);
+
+    scroll_position.0 = offset;
+}
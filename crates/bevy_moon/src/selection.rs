@@ -0,0 +1,374 @@
+//! Mouse selection for read-only text.
+
+use bevy_app::{App, Plugin, Update};
+use bevy_camera::Camera;
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    message::MessageReader,
+    observer::On,
+    query::{Changed, With},
+    reflect::ReflectComponent,
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{ButtonInput, keyboard::KeyCode};
+use bevy_math::Vec2;
+use bevy_picking::{
+    events::{Drag, DragStart, Pointer, Press},
+    hover::HoverMap,
+    pointer::{PointerButton, PointerInput},
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_text::{ComputedTextBlock, TextLayoutInfo};
+use bevy_transform::components::GlobalTransform;
+use cosmic_text::Cursor;
+
+use crate::{
+    computed::ComputedNode, elements::text::Text, interaction::DisabledQuery,
+    picking::pointer_local_point,
+};
+
+/// Makes a [`Text`] node selectable with the mouse.
+///
+/// The selection is stored in [`TextSelection`], and copied with `Ctrl+C` (`Cmd+C` on macOS)
+/// through a [`TextCopy`] event.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Text, TextSelection)]
+pub struct SelectableText;
+
+/// The selected range of a [`SelectableText`] node, in cosmic-text cursor positions.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TextSelection {
+    /// Where the selection started.
+    pub anchor: Option<Cursor>,
+    /// Where the selection ends, follows the pointer while dragging.
+    pub focus: Option<Cursor>,
+    /// The camera of the current drag.
+    camera: Option<Entity>,
+}
+
+impl TextSelection {
+    /// Returns the ordered selection range, or `None` if nothing is selected.
+    pub fn range(&self) -> Option<(Cursor, Cursor)> {
+        let (Some(anchor), Some(focus)) = (self.anchor, self.focus) else {
+            return None;
+        };
+
+        if (anchor.line, anchor.index) == (focus.line, focus.index) {
+            return None;
+        }
+
+        Some((anchor.min(focus), anchor.max(focus)))
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.range().is_none()
+    }
+
+    #[inline]
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.focus = None;
+    }
+
+    /// Returns the selected string of the text block.
+    pub fn selected_text(&self, computed_text_block: &ComputedTextBlock) -> String {
+        let Some((start, end)) = self.range() else {
+            return String::new();
+        };
+
+        let lines = &computed_text_block.buffer().lines;
+        let mut selected = String::new();
+
+        for (line_index, line) in lines.iter().enumerate().take(end.line + 1).skip(start.line) {
+            let text = line.text();
+
+            let from = if line_index == start.line {
+                start.index.min(text.len())
+            } else {
+                0
+            };
+            let to = if line_index == end.line {
+                end.index.min(text.len())
+            } else {
+                text.len()
+            };
+
+            if line_index != start.line {
+                selected.push('\n');
+            }
+            selected.push_str(text.get(from..to).unwrap_or_default());
+        }
+
+        selected
+    }
+}
+
+/// The color of text selection highlights.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TextSelectionColor(pub Color);
+
+impl Default for TextSelectionColor {
+    fn default() -> Self {
+        Self(Color::srgba(0.2, 0.45, 0.95, 0.35))
+    }
+}
+
+/// The [`SelectableText`] node which owns the most recent selection.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct ActiveTextSelection(pub Option<Entity>);
+
+/// Triggered on a [`SelectableText`] node when its selection is copied.
+///
+/// Bevy has no clipboard, so apps forward the string to their own clipboard.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TextCopy {
+    pub entity: Entity,
+    pub text: String,
+}
+
+/// A plugin that adds mouse selection for [`SelectableText`] nodes.
+pub struct TextSelectionPlugin;
+
+impl Plugin for TextSelectionPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TextSelectionColor>()
+            .init_resource::<ActiveTextSelection>()
+            .add_observer(on_text_press)
+            .add_observer(on_text_drag_start)
+            .add_observer(on_text_drag)
+            .add_systems(
+                Update,
+                (
+                    clear_pressed_out_text_selection,
+                    clear_changed_text_selections,
+                    copy_text_selection,
+                ),
+            );
+    }
+}
+
+fn on_text_press(
+    mut event: On<Pointer<Press>>,
+    mut text_query: Query<
+        (
+            &mut TextSelection,
+            &GlobalTransform,
+            &ComputedNode,
+            &ComputedTextBlock,
+            &TextLayoutInfo,
+        ),
+        With<SelectableText>,
+    >,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    disabled_query: DisabledQuery,
+    mut active: ResMut<ActiveTextSelection>,
+) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    let entity = event.entity;
+    let Ok((mut selection, transform, computed_node, computed_text_block, text_layout_info)) =
+        text_query.get_mut(entity)
+    else {
+        return;
+    };
+
+    if disabled_query.is_disabled(entity) {
+        return;
+    }
+
+    // Pressing starts a new, collapsed selection.
+    let cursor = pointer_local_point(
+        &cameras,
+        event.hit.camera,
+        event.pointer_location.position,
+        transform,
+    )
+    .and_then(|local_point| {
+        hit_text(
+            local_point,
+            computed_node,
+            computed_text_block,
+            text_layout_info,
+        )
+    });
+
+    selection.anchor = cursor;
+    selection.focus = cursor;
+    selection.camera = Some(event.hit.camera);
+
+    if let Some(previous) = active.0.replace(entity).filter(|&e| e != entity)
+        && let Ok((mut previous, ..)) = text_query.get_mut(previous)
+    {
+        previous.clear();
+    }
+
+    event.propagate(false);
+}
+
+fn on_text_drag_start(
+    mut event: On<Pointer<DragStart>>,
+    text_query: Query<(), With<SelectableText>>,
+) {
+    // Keeps draggable ancestors in place while selecting.
+    if text_query.contains(event.entity) {
+        event.propagate(false);
+    }
+}
+
+fn on_text_drag(
+    mut event: On<Pointer<Drag>>,
+    mut text_query: Query<
+        (
+            &mut TextSelection,
+            &GlobalTransform,
+            &ComputedNode,
+            &ComputedTextBlock,
+            &TextLayoutInfo,
+        ),
+        With<SelectableText>,
+    >,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    disabled_query: DisabledQuery,
+) {
+    let Ok((mut selection, transform, computed_node, computed_text_block, text_layout_info)) =
+        text_query.get_mut(event.entity)
+    else {
+        return;
+    };
+
+    if disabled_query.is_disabled(event.entity) {
+        return;
+    }
+
+    event.propagate(false);
+
+    if event.button != PointerButton::Primary || selection.anchor.is_none() {
+        return;
+    }
+
+    let Some(local_point) = selection.camera.and_then(|camera| {
+        pointer_local_point(&cameras, camera, event.pointer_location.position, transform)
+    }) else {
+        return;
+    };
+
+    if let Some(cursor) = hit_text(
+        local_point,
+        computed_node,
+        computed_text_block,
+        text_layout_info,
+    ) {
+        selection.focus = Some(cursor);
+    }
+}
+
+/// Maps a node-local point into a cursor position of the text buffer.
+fn hit_text(
+    local_point: Vec2,
+    computed_node: &ComputedNode,
+    computed_text_block: &ComputedTextBlock,
+    text_layout_info: &TextLayoutInfo,
+) -> Option<Cursor> {
+    let scale_factor = text_layout_info.scale_factor;
+
+    // From the center of the node (y up) to the top-left of the text buffer (y down).
+    let x = (local_point.x + 0.5 * computed_node.size.x) * scale_factor;
+    let y = (0.5 * computed_node.size.y - local_point.y) * scale_factor;
+
+    let buffer = computed_text_block.buffer();
+
+    buffer.hit(x, y.clamp(0.0, buffer_height(computed_text_block)))
+}
+
+fn buffer_height(computed_text_block: &ComputedTextBlock) -> f32 {
+    computed_text_block
+        .buffer()
+        .layout_runs()
+        .last()
+        .map(|run| run.line_top + run.line_height - 1.0)
+        .unwrap_or_default()
+        .max(0.0)
+}
+
+/// Clears the active selection when the primary button is pressed outside of its text,
+/// e.g. on another node or on the empty space.
+fn clear_pressed_out_text_selection(
+    hover_map: Res<HoverMap>,
+    mut input_reader: MessageReader<PointerInput>,
+    mut active: ResMut<ActiveTextSelection>,
+    mut text_query: Query<&mut TextSelection, With<SelectableText>>,
+) {
+    let Some(entity) = active.0 else {
+        input_reader.clear();
+        return;
+    };
+
+    let pressed_out = input_reader.read().any(|input| {
+        input.button_just_pressed(PointerButton::Primary)
+            && hover_map
+                .get(&input.pointer_id)
+                .is_none_or(|hits| !hits.contains_key(&entity))
+    });
+    if !pressed_out {
+        return;
+    }
+
+    active.0 = None;
+    if let Ok(mut selection) = text_query.get_mut(entity) {
+        selection.clear();
+    }
+}
+
+/// The old cursors are invalid after the text content changes.
+fn clear_changed_text_selections(
+    mut text_query: Query<&mut TextSelection, (Changed<Text>, With<SelectableText>)>,
+) {
+    for mut selection in text_query.iter_mut() {
+        if selection.anchor.is_some() {
+            selection.clear();
+        }
+    }
+}
+
+fn copy_text_selection(
+    keys: Res<ButtonInput<KeyCode>>,
+    active: Res<ActiveTextSelection>,
+    text_query: Query<(&TextSelection, &ComputedTextBlock), With<SelectableText>>,
+    mut commands: Commands,
+) {
+    let modifier = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    if !modifier || !keys.just_pressed(KeyCode::KeyC) {
+        return;
+    }
+
+    let Some(entity) = active.0 else {
+        return;
+    };
+
+    let Ok((selection, computed_text_block)) = text_query.get(entity) else {
+        return;
+    };
+
+    if selection.is_empty() {
+        return;
+    }
+
+    commands.trigger(TextCopy {
+        entity,
+        text: selection.selected_text(computed_text_block),
+    });
+}
@@ -8,9 +8,9 @@ use bevy_ecs::{
     change_detection::DetectChangesMut,
     entity::{Entity, EntityHashMap},
     hierarchy::{ChildOf, Children},
-    query::{With, Without},
+    query::{Has, Or, With, Without},
     resource::Resource,
-    system::{Local, Query, ResMut},
+    system::{Local, Query, ResMut, SystemParam},
 };
 use bevy_render::extract_resource::ExtractResource;
 use bevy_transform::components::GlobalTransform;
@@ -18,7 +18,11 @@ use fixedbitset::FixedBitSet;
 use smallvec::SmallVec;
 
 use crate::{
-    computed::ComputedNode, elements::node::Node, utils::SORT_ENTITIES_FROM_BACK_TO_FRONT,
+    computed::ComputedNode,
+    elements::node::{GlobalZIndex, Node, TopLayer, ZIndex},
+    overlay::Overlay,
+    screen_space::ScreenSpace,
+    utils::SORT_ENTITIES_FROM_BACK_TO_FRONT,
 };
 
 /// The current UI stack, which contains all UI nodes ordered by their depth (back-to-front).
@@ -38,6 +42,9 @@ pub struct UiStack {
 
     /// Stores the bitset.
     pub bitset: FixedBitSet,
+
+    /// The order of the camera.
+    pub order: isize,
 }
 
 #[derive(Debug, Clone, Resource, ExtractResource, Default)]
@@ -63,27 +70,140 @@ impl DerefMut for UiStackMap {
     }
 }
 
+impl UiStackMap {
+    /// Returns the camera which renders the node.
+    ///
+    /// If several cameras render the node, the one with the lowest order is returned.
+    pub fn find_camera(&self, entity: Entity) -> Option<Entity> {
+        self.0
+            .iter()
+            .filter(|(_, ui_stack)| ui_stack.bitset.contains(entity.index_u32() as usize))
+            .min_by_key(|(camera_entity, ui_stack)| (ui_stack.order, **camera_entity))
+            .map(|(camera_entity, _)| *camera_entity)
+    }
+}
+
+type RootNodeData = (
+    Entity,
+    &'static GlobalTransform,
+    Option<&'static Children>,
+    Option<&'static ZIndex>,
+    Option<&'static GlobalZIndex>,
+    Has<ChildOf>,
+    Has<Overlay>,
+);
+
+type RootNodeFilter = (
+    With<Node>,
+    Or<(Without<ChildOf>, With<Overlay>, With<GlobalZIndex>)>,
+);
+
+type StackNodeData = (
+    Entity,
+    &'static GlobalTransform,
+    Option<&'static Children>,
+    Option<&'static ZIndex>,
+);
+
+type StackNodeFilter = (
+    With<Node>,
+    With<ChildOf>,
+    Without<Overlay>,
+    Without<GlobalZIndex>,
+);
+
+/// The overlays and screen space roots, which are rendered by one camera and stacked in their
+/// own layers.
+#[derive(SystemParam)]
+pub struct UiLayers<'w, 's> {
+    overlay_query: Query<'w, 's, (Entity, &'static Overlay)>,
+    screen_space_query: Query<'w, 's, (Entity, &'static ScreenSpace)>,
+    layer_query: Query<'w, 's, (Has<TopLayer>, Has<ScreenSpace>)>,
+    parent_query: Query<'w, 's, &'static ChildOf>,
+    children_query: Query<'w, 's, &'static Children>,
+}
+
+/// The buffers of [`ui_stack_system`], kept between the runs.
+#[derive(Default)]
+pub struct UiStackBuffers {
+    /// The cameras sorted by their order, with the nodes they render.
+    cameras: Vec<(Entity, isize, FixedBitSet)>,
+    /// The overlays and screen space roots, with the camera which renders them.
+    pinned_roots: Vec<(Entity, Entity)>,
+    pending: Vec<Entity>,
+}
+
 pub fn ui_stack_system(
-    render_targets: Query<(Entity, &VisibleEntities), With<Camera>>,
-    root_node_query: Query<
-        (Entity, &GlobalTransform, Option<&Children>),
-        (With<Node>, Without<ChildOf>),
-    >,
-    node_query: Query<(Entity, &GlobalTransform, Option<&Children>), (With<Node>, With<ChildOf>)>,
+    render_targets: Query<(Entity, &Camera, &VisibleEntities)>,
+    root_node_query: Query<RootNodeData, RootNodeFilter>,
+    node_query: Query<StackNodeData, StackNodeFilter>,
+    layers: UiLayers,
     mut update_query: Query<&mut ComputedNode>,
     mut ui_stack_map: ResMut<UiStackMap>,
-    mut view_entities: Local<FixedBitSet>,
+    mut buffers: Local<UiStackBuffers>,
 ) {
+    let UiStackBuffers {
+        cameras,
+        pinned_roots,
+        pending,
+    } = &mut *buffers;
+
     ui_stack_map.clear();
 
-    for (camera_entity, visiable_entities) in render_targets {
-        view_entities.clear();
-        view_entities.extend(
-            visiable_entities
-                .get(TypeId::of::<Node>())
-                .iter()
-                .map(|e| e.index_u32() as usize),
-        );
+    cameras.clear();
+    cameras.extend(
+        render_targets
+            .iter()
+            .map(|(camera_entity, camera, visiable_entities)| {
+                let view_entities = visiable_entities
+                    .get(TypeId::of::<Node>())
+                    .iter()
+                    .map(|e| e.index_u32() as usize)
+                    .collect::<FixedBitSet>();
+                (camera_entity, camera.order, view_entities)
+            }),
+    );
+    cameras.sort_by_key(|(camera_entity, order, _)| (*order, *camera_entity));
+
+    // An overlay or a screen space root is only rendered by one camera, along with its subtree.
+    pinned_roots.clear();
+    pinned_roots.extend(
+        layers
+            .overlay_query
+            .iter()
+            .map(|(entity, _)| entity)
+            .chain(layers.screen_space_query.iter().map(|(entity, _)| entity))
+            .filter_map(|entity| {
+                layers
+                    .pinned_camera(entity, cameras)
+                    .map(|camera_entity| (entity, camera_entity))
+            }),
+    );
+
+    for (camera_entity, order, view_entities) in cameras.iter_mut() {
+        let camera_entity = *camera_entity;
+
+        for &(root, pinned_camera) in pinned_roots.iter() {
+            // The nested overlays are pinned on their own.
+            pending.push(root);
+            while let Some(entity) = pending.pop() {
+                let index = entity.index_u32() as usize;
+                if pinned_camera == camera_entity {
+                    view_entities.grow_and_insert(index);
+                } else if view_entities.contains(index) {
+                    view_entities.remove(index);
+                }
+
+                pending.extend(
+                    layers
+                        .children_query
+                        .get(entity)
+                        .into_iter()
+                        .flatten()
+                        .filter(|&&child| !layers.is_pinned(child)),
+                );
+            }
+        }
 
         if view_entities.is_clear() {
             continue;
@@ -91,36 +211,131 @@ pub fn ui_stack_system(
 
         let ui_stack = ui_stack_map.as_mut().entry(camera_entity).or_default();
 
-        ui_stack.bitset.union_with(&view_entities);
+        ui_stack.order = *order;
+        ui_stack.bitset.union_with(view_entities);
 
-        // Only filter root nodes.
-        let nodes = root_node_query
+        // Only filter root nodes, and the nodes stacked as roots by their `GlobalZIndex`.
+        let (top_layer_nodes, nodes): (Vec<_>, Vec<_>) = root_node_query
             .iter()
             .filter(|entity| ui_stack.bitset.contains(entity.0.index_u32() as usize))
-            .collect::<Vec<_>>();
+            .partition(|entity| layers.layer(entity.0).0);
+        let (screen_space_nodes, nodes): (Vec<_>, Vec<_>) = nodes
+            .into_iter()
+            .partition(|entity| layers.layer(entity.0).1);
 
-        ui_stack.roots.extend(nodes.iter().map(|e| e.0));
+        // Nodes with a `GlobalZIndex` are still laid out and clipped by their parents.
+        ui_stack.roots.extend(
+            nodes
+                .iter()
+                .chain(&screen_space_nodes)
+                .chain(&top_layer_nodes)
+                .filter(|(.., has_parent, is_overlay)| !has_parent || *is_overlay)
+                .map(|e| e.0),
+        );
 
         // Make sure ui transparency phases' `sort_key` is correct.
         let mut depth = 0;
 
-        update_ui_stack_recursive(
-            &node_query,
-            &mut update_query,
-            ui_stack,
-            &mut depth,
-            nodes,
-            camera_entity,
-        );
+        // The screen space roots are stacked above the world space roots, then the top layer.
+        for nodes in [nodes, screen_space_nodes, top_layer_nodes] {
+            update_ui_stack_recursive(
+                &node_query,
+                &mut update_query,
+                ui_stack,
+                &mut depth,
+                nodes
+                    .into_iter()
+                    .map(
+                        |(entity, transform, children, z_index, global_z_index, ..)| {
+                            (
+                                entity,
+                                transform,
+                                children,
+                                [
+                                    global_z_index.map_or(0, |z| z.0),
+                                    z_index.map_or(0, |z| z.0),
+                                ],
+                            )
+                        },
+                    )
+                    .collect(),
+                camera_entity,
+            );
+        }
     }
 }
 
+impl UiLayers<'_, '_> {
+    /// Returns `true` if the node is an overlay or a screen space root.
+    fn is_pinned(&self, entity: Entity) -> bool {
+        self.overlay_query.contains(entity) || self.screen_space_query.contains(entity)
+    }
+
+    /// Returns whether the node is in the top layer and in screen space.
+    ///
+    /// The layer of a node is chosen by its root, so a node stacked as a root by its
+    /// `GlobalZIndex` stays in the layer of the modal, overlay or screen space root it's in.
+    fn layer(&self, entity: Entity) -> (bool, bool) {
+        let root = core::iter::once(entity)
+            .chain(self.parent_query.iter_ancestors(entity))
+            .find(|&e| !self.parent_query.contains(e) || self.overlay_query.contains(e))
+            .unwrap_or(entity);
+        self.layer_query.get(root).unwrap_or_default()
+    }
+
+    /// Returns the camera which renders an overlay or a screen space root.
+    ///
+    /// A screen space root without a camera is rendered by the first camera rendering it, and an
+    /// overlay without a camera follows its anchor, so it's rendered by the camera of the overlay
+    /// or screen space root the anchor is in, otherwise by the first camera rendering the anchor.
+    fn pinned_camera(
+        &self,
+        mut entity: Entity,
+        cameras: &[(Entity, isize, FixedBitSet)],
+    ) -> Option<Entity> {
+        let first_camera = |entity: Entity| {
+            cameras
+                .iter()
+                .find(|(.., view_entities)| view_entities.contains(entity.index_u32() as usize))
+                .map(|(camera_entity, ..)| *camera_entity)
+        };
+        let mut visited = SmallVec::<[Entity; 4]>::new();
+
+        loop {
+            // The overlays are anchored in each other.
+            if visited.contains(&entity) {
+                return None;
+            }
+            visited.push(entity);
+
+            if let Ok((_, screen_space)) = self.screen_space_query.get(entity) {
+                return screen_space.camera.or_else(|| first_camera(entity));
+            }
+
+            let (_, overlay) = self.overlay_query.get(entity).ok()?;
+            if overlay.camera.is_some() {
+                return overlay.camera;
+            }
+
+            let anchor = overlay.anchor;
+            match core::iter::once(anchor)
+                .chain(self.parent_query.iter_ancestors(anchor))
+                .find(|&e| self.is_pinned(e))
+            {
+                Some(root) => entity = root,
+                None => return first_camera(anchor),
+            }
+        }
+    }
+}
+
+/// Stacks the nodes by their `[GlobalZIndex, ZIndex]`, then by their z.
 fn update_ui_stack_recursive(
-    node_query: &Query<(Entity, &GlobalTransform, Option<&Children>), (With<Node>, With<ChildOf>)>,
+    node_query: &Query<StackNodeData, StackNodeFilter>,
     update_query: &mut Query<&mut ComputedNode>,
     ui_stack: &mut UiStack,
     depth: &mut usize,
-    mut sorted_nodes: Vec<(Entity, &GlobalTransform, Option<&Children>)>,
+    mut sorted_nodes: Vec<(Entity, &GlobalTransform, Option<&Children>, [i32; 2])>,
     camera_entity: Entity,
 ) {
     if sorted_nodes.is_empty() {
@@ -128,7 +343,8 @@ fn update_ui_stack_recursive(
     }
 
     radsort::sort_by_key(&mut sorted_nodes, |e| {
-        SORT_ENTITIES_FROM_BACK_TO_FRONT(e.0, *e.1)
+        let (z, index) = SORT_ENTITIES_FROM_BACK_TO_FRONT(e.0, *e.1);
+        (e.3[0], e.3[1], z, index)
     });
 
     tracing::debug!(
@@ -149,7 +365,7 @@ fn update_ui_stack_recursive(
     let end = start + sorted_nodes.len();
     ui_stack.ranges.push(start..end);
 
-    for (entity, _transform, children) in sorted_nodes {
+    for (entity, _transform, children, _) in sorted_nodes {
         if let Ok(mut computed_node) = update_query.get_mut(entity) {
             computed_node.bypass_change_detection().stack_index = *depth;
         }
@@ -162,7 +378,12 @@ fn update_ui_stack_recursive(
             continue;
         };
 
-        let nodes = node_query.iter_many(children).collect::<Vec<_>>();
+        let nodes = node_query
+            .iter_many(children)
+            .map(|(entity, transform, children, z_index)| {
+                (entity, transform, children, [0, z_index.map_or(0, |z| z.0)])
+            })
+            .collect::<Vec<_>>();
 
         update_ui_stack_recursive(
             &node_query,
@@ -0,0 +1,300 @@
+//! Style variants driven by the [`Interaction`] states.
+
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::{Entity, EntityHashSet},
+    lifecycle::RemovedComponents,
+    schedule::IntoScheduleConfigs,
+    system::{Local, Query},
+    world::Ref,
+};
+
+use crate::{
+    interaction::{Checked, Interaction},
+    style::{BorderColor, BoxShadow, Corners, Outline, Style},
+    transition::{AnimatableStyle, TransitionState},
+};
+
+/// A partial [`Style`], the fields which are set override the base style.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct StyleVariant {
+    pub background: Option<Color>,
+    pub border_color: Option<BorderColor>,
+    pub corner_radii: Option<Corners<f32>>,
+    pub box_shadow: Option<BoxShadow>,
+    pub outline: Option<Outline>,
+}
+
+// SAFETY: The taffy lengths of the `Outline` are only thread unsafe with a `calc` value,
+// which moon does not implement, the same as for `Style`.
+unsafe impl Sync for StyleVariant {}
+// SAFETY: See the `Sync` impl.
+unsafe impl Send for StyleVariant {}
+
+impl StyleVariant {
+    pub const DEFAULT: Self = Self {
+        background: None,
+        border_color: None,
+        corner_radii: None,
+        box_shadow: None,
+        outline: None,
+    };
+
+    #[inline]
+    pub const fn new() -> Self {
+        Self::DEFAULT
+    }
+
+    #[inline]
+    pub fn background<T>(mut self, color: T) -> Self
+    where
+        T: Into<Color>,
+    {
+        self.background = Some(color.into());
+        self
+    }
+
+    #[inline]
+    pub const fn border_color(mut self, color: BorderColor) -> Self {
+        self.border_color = Some(color);
+        self
+    }
+
+    #[inline]
+    pub const fn corner_radii(mut self, radii: Corners<f32>) -> Self {
+        self.corner_radii = Some(radii);
+        self
+    }
+
+    #[inline]
+    pub const fn box_shadow(mut self, shadow: BoxShadow) -> Self {
+        self.box_shadow = Some(shadow);
+        self
+    }
+
+    #[inline]
+    pub const fn outline(mut self, outline: Outline) -> Self {
+        self.outline = Some(outline);
+        self
+    }
+}
+
+/// Alternate styles of a node per [`Interaction`] state, likes CSS pseudo-classes.
+///
+/// The active variants are merged onto the base [`Style`] in the order:
+/// `checked`, `focused`, `hovered`, `pressed`. A disabled node only uses the `disabled` variant.
+///
+/// The `checked` variant is active while the node has the [`Checked`] marker.
+///
+/// The base is captured from [`Style`], changing the [`Style`] directly updates the base.
+/// Adds a [`Transition`](crate::transition::Transition) to animate the changes.
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+#[require(Interaction, StyleStatesBase)]
+pub struct StyleStates {
+    pub hovered: Option<StyleVariant>,
+    pub pressed: Option<StyleVariant>,
+    pub focused: Option<StyleVariant>,
+    pub disabled: Option<StyleVariant>,
+    pub checked: Option<StyleVariant>,
+}
+
+impl StyleStates {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            hovered: None,
+            pressed: None,
+            focused: None,
+            disabled: None,
+            checked: None,
+        }
+    }
+
+    #[inline]
+    pub const fn hovered(mut self, variant: StyleVariant) -> Self {
+        self.hovered = Some(variant);
+        self
+    }
+
+    #[inline]
+    pub const fn pressed(mut self, variant: StyleVariant) -> Self {
+        self.pressed = Some(variant);
+        self
+    }
+
+    #[inline]
+    pub const fn focused(mut self, variant: StyleVariant) -> Self {
+        self.focused = Some(variant);
+        self
+    }
+
+    #[inline]
+    pub const fn disabled(mut self, variant: StyleVariant) -> Self {
+        self.disabled = Some(variant);
+        self
+    }
+
+    #[inline]
+    pub const fn checked(mut self, variant: StyleVariant) -> Self {
+        self.checked = Some(variant);
+        self
+    }
+
+    /// Returns the active variants of the interaction, from low to high priority.
+    pub fn active_variants(
+        &self,
+        interaction: &Interaction,
+        checked: bool,
+    ) -> impl Iterator<Item = &StyleVariant> {
+        let variants = if interaction.disabled {
+            [None, None, None, self.disabled.as_ref()]
+        } else {
+            [
+                self.checked.as_ref().filter(|_| checked),
+                self.focused.as_ref().filter(|_| interaction.focused),
+                self.hovered.as_ref().filter(|_| interaction.hovered),
+                self.pressed.as_ref().filter(|_| interaction.pressed),
+            ]
+        };
+
+        variants.into_iter().flatten()
+    }
+}
+
+/// The visual fields of a [`Style`] which can be overridden by a [`StyleVariant`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub(crate) struct VisualStyle {
+    pub background: Option<Color>,
+    pub border_color: Option<BorderColor>,
+    pub corner_radii: Corners<f32>,
+    pub box_shadow: Option<BoxShadow>,
+    pub outline: Option<Outline>,
+}
+
+// SAFETY: Holds the same `Outline` as `StyleVariant`, without a `calc` value.
+unsafe impl Sync for VisualStyle {}
+// SAFETY: See the `Sync` impl.
+unsafe impl Send for VisualStyle {}
+
+impl VisualStyle {
+    pub fn from_style(style: &Style) -> Self {
+        Self {
+            background: style.background,
+            border_color: style.border_color,
+            corner_radii: style.corner_radii,
+            box_shadow: style.box_shadow,
+            outline: style.outline,
+        }
+    }
+
+    pub fn from_animatable(style: &AnimatableStyle) -> Self {
+        Self {
+            background: style.background,
+            border_color: style.border_color,
+            corner_radii: style.corner_radii,
+            box_shadow: style.box_shadow,
+            outline: style.outline,
+        }
+    }
+
+    pub fn apply(&self, style: &mut Style) {
+        style.background = self.background;
+        style.border_color = self.border_color;
+        style.corner_radii = self.corner_radii;
+        style.box_shadow = self.box_shadow;
+        style.outline = self.outline;
+    }
+
+    pub fn merge(mut self, variant: &StyleVariant) -> Self {
+        if let Some(background) = variant.background {
+            self.background = Some(background);
+        }
+        if let Some(border_color) = variant.border_color {
+            self.border_color = Some(border_color);
+        }
+        if let Some(corner_radii) = variant.corner_radii {
+            self.corner_radii = corner_radii;
+        }
+        if let Some(box_shadow) = variant.box_shadow {
+            self.box_shadow = Some(box_shadow);
+        }
+        if let Some(outline) = variant.outline {
+            self.outline = Some(outline);
+        }
+        self
+    }
+}
+
+/// The base style of [`StyleStates`] and the last merged result.
+#[derive(Component, Clone, Debug, Default)]
+pub struct StyleStatesBase {
+    base: Option<VisualStyle>,
+    applied: VisualStyle,
+}
+
+/// A plugin that applies [`StyleStates`].
+pub struct StyleStatesPlugin;
+
+impl Plugin for StyleStatesPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(PostUpdate, style_states_system.in_set(AnimationSystems));
+    }
+}
+
+type StyleStatesData = (
+    Entity,
+    Ref<'static, StyleStates>,
+    Ref<'static, Interaction>,
+    Option<Ref<'static, Checked>>,
+    &'static mut StyleStatesBase,
+    &'static mut Style,
+    Option<&'static TransitionState>,
+);
+
+pub fn style_states_system(
+    mut query: Query<StyleStatesData>,
+    mut removed_checked: RemovedComponents<Checked>,
+    mut unchecked: Local<EntityHashSet>,
+) {
+    unchecked.clear();
+    unchecked.extend(removed_checked.read());
+
+    for (entity, states, interaction, checked, mut states_base, mut style, transition) in
+        query.iter_mut()
+    {
+        if !states.is_changed()
+            && !interaction.is_changed()
+            && !style.is_changed()
+            && !checked.as_ref().is_some_and(DetectChanges::is_added)
+            && !unchecked.contains(&entity)
+        {
+            continue;
+        }
+
+        // A running transition writes the intermediate values, compares with its target instead.
+        let current = match transition.and_then(TransitionState::target) {
+            Some(target) => VisualStyle::from_animatable(target),
+            None => VisualStyle::from_style(&style),
+        };
+
+        // The style has been changed outside, it becomes the new base.
+        let base = match states_base.base {
+            Some(base) if current == states_base.applied => base,
+            _ => current,
+        };
+
+        let merged = states
+            .active_variants(&interaction, checked.is_some())
+            .fold(base, VisualStyle::merge);
+
+        states_base.base = Some(base);
+        states_base.applied = merged;
+
+        if merged != current {
+            merged.apply(&mut style);
+        }
+    }
+}
@@ -273,6 +273,9 @@ pub struct Style {
     #[reflect(ignore, clone)]
     pub outline: Option<Outline>,
 
+    /// The opacity of the node and its descendants, in the range `0.0..=1.0`.
+    pub opacity: f32,
+
     pub clip_rect: Option<Rect>,
     pub overflow_clip_margin: OverflowClipMargin,
 }
@@ -305,6 +308,7 @@ impl Style {
         corner_radii: Corners::DEFAULT,
         box_shadow: None,
         outline: None,
+        opacity: 1.0,
 
         clip_rect: None,
         overflow_clip_margin: OverflowClipMargin::DEFAULT,
@@ -353,6 +357,12 @@ impl Style {
         self
     }
 
+    #[inline]
+    pub const fn opacity(mut self, opacity: f32) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
     pub fn overflow_clip_margin(mut self, overflow_clip_margin: OverflowClipMargin) -> Self {
         self.overflow_clip_margin = overflow_clip_margin;
         self
@@ -2,25 +2,29 @@ use bevy_ecs::{
     change_detection::DetectChangesMut,
     entity::Entity,
     hierarchy::Children,
-    query::Has,
+    query::{Has, Without},
     system::{Query, Res},
 };
 use bevy_math::Rect;
 use bevy_transform::components::GlobalTransform;
 
 use crate::{
-    computed::ComputedNode, elements::node::OverrideClip, stack::UiStackMap, style::Style,
+    computed::ComputedNode, elements::node::OverrideClip, overlay::Overlay, stack::UiStackMap,
+    style::Style,
 };
 
+type ClipNodeData = (
+    Entity,
+    &'static ComputedNode,
+    &'static GlobalTransform,
+    Option<&'static Children>,
+    Has<OverrideClip>,
+);
+
 pub fn ui_clipping_system(
     ui_stack_map: Res<UiStackMap>,
-    node_query: Query<(
-        Entity,
-        &ComputedNode,
-        &GlobalTransform,
-        Option<&Children>,
-        Has<OverrideClip>,
-    )>,
+    root_node_query: Query<ClipNodeData>,
+    node_query: Query<ClipNodeData, Without<Overlay>>,
     mut update_style: Query<&mut Style>,
 ) {
     // root nodes
@@ -28,19 +32,14 @@ pub fn ui_clipping_system(
         .iter()
         .flat_map(|(_, ui_stack)| ui_stack.roots.as_ref());
 
-    for node in node_query.iter_many(entities) {
+    // Overlays are clipped as roots.
+    for node in root_node_query.iter_many(entities) {
         ui_clipping_recursive(&node_query, &mut update_style, node, None);
     }
 }
 
 fn ui_clipping_recursive(
-    node_query: &Query<(
-        Entity,
-        &ComputedNode,
-        &GlobalTransform,
-        Option<&Children>,
-        Has<OverrideClip>,
-    )>,
+    node_query: &Query<ClipNodeData, Without<Overlay>>,
     update_style: &mut Query<&mut Style>,
     (entity, computed_node, transform, children, has_override_clip): (
         Entity,
@@ -0,0 +1,212 @@
+//! Tooltips of hovered UI nodes.
+
+use core::time::Duration;
+
+use bevy_app::{App, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    children,
+    component::Component,
+    entity::Entity,
+    message::MessageReader,
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{ButtonInput, mouse::MouseButton, mouse::MouseWheel};
+use bevy_picking::Pickable;
+use bevy_text::{TextColor, TextFont};
+use bevy_time::Time;
+use taffy::{Dimension, LengthPercentage};
+
+use crate::{
+    computed::ComputedNode,
+    elements::text::Text,
+    interaction::Interaction,
+    overlay::{Overlay, OverlayPlacement},
+    style::{Corners, Style},
+};
+
+/// Shows a popup with the text after the node is hovered for a while.
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(Interaction)]
+pub struct Tooltip {
+    pub text: String,
+    /// How long the node is hovered before showing the popup.
+    pub delay: Duration,
+    /// The preferred side of the node, flips to the opposite side if there is no room.
+    pub placement: OverlayPlacement,
+    /// The distance between the node and the popup.
+    pub gap: f32,
+}
+
+impl Tooltip {
+    #[inline]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            delay: Duration::from_millis(500),
+            placement: OverlayPlacement::Bottom,
+            gap: 6.0,
+        }
+    }
+
+    #[inline]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    #[inline]
+    pub const fn placement(mut self, placement: OverlayPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    #[inline]
+    pub const fn gap(mut self, gap: f32) -> Self {
+        self.gap = gap;
+        self
+    }
+}
+
+/// The look of the tooltip popups.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TooltipStyle {
+    pub background: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub padding: f32,
+    pub corner_radius: f32,
+    pub max_width: f32,
+}
+
+impl Default for TooltipStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::srgba(0.1, 0.1, 0.12, 0.95),
+            text_color: Color::WHITE,
+            font_size: 13.0,
+            padding: 6.0,
+            corner_radius: 4.0,
+            max_width: 280.0,
+        }
+    }
+}
+
+/// The popup of a [`Tooltip`], spawned as an [`Overlay`] of the node.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TooltipPopup {
+    pub anchor: Entity,
+}
+
+/// The tooltip of the hovered node.
+#[derive(Resource, Clone, Copy, Debug, Default)]
+pub struct ActiveTooltip {
+    anchor: Option<Entity>,
+    popup: Option<Entity>,
+    elapsed: Duration,
+    /// Hidden by a scroll or a press, until the pointer leaves the node.
+    suppressed: bool,
+}
+
+impl ActiveTooltip {
+    /// Returns the node whose popup is shown.
+    #[inline]
+    pub fn shown(&self) -> Option<Entity> {
+        self.popup.and(self.anchor)
+    }
+
+    fn hide(&mut self, commands: &mut Commands) {
+        if let Some(popup) = self.popup.take() {
+            commands.entity(popup).try_despawn();
+        }
+    }
+}
+
+/// A plugin that shows [`Tooltip`]s.
+pub struct TooltipPlugin;
+
+impl Plugin for TooltipPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TooltipStyle>()
+            .init_resource::<ActiveTooltip>()
+            .add_systems(Update, tooltip_hover_system);
+    }
+}
+
+pub fn tooltip_hover_system(
+    time: Res<Time>,
+    tooltip_style: Res<TooltipStyle>,
+    mouse: Option<Res<ButtonInput<MouseButton>>>,
+    mut wheel: MessageReader<MouseWheel>,
+    tooltip_query: Query<(Entity, &Tooltip, &Interaction, &ComputedNode)>,
+    mut active: ResMut<ActiveTooltip>,
+    mut commands: Commands,
+) {
+    let hovered = tooltip_query
+        .iter()
+        .filter(|(_, _, interaction, _)| interaction.hovered && !interaction.disabled)
+        .max_by_key(|(.., computed_node)| computed_node.stack_index)
+        .map(|(entity, tooltip, ..)| (entity, tooltip));
+
+    if active.anchor != hovered.map(|(entity, _)| entity) {
+        active.hide(&mut commands);
+        *active = ActiveTooltip {
+            anchor: hovered.map(|(entity, _)| entity),
+            ..Default::default()
+        };
+    }
+
+    let scrolled = wheel.read().count() > 0;
+    let pressed = mouse.is_some_and(|mouse| mouse.get_just_pressed().next().is_some());
+    if scrolled || pressed {
+        active.hide(&mut commands);
+        active.suppressed = true;
+    }
+
+    let Some((anchor, tooltip)) = hovered else {
+        return;
+    };
+
+    if active.popup.is_some() || active.suppressed {
+        return;
+    }
+
+    active.elapsed += time.delta();
+    if active.elapsed < tooltip.delay {
+        return;
+    }
+
+    let popup = commands
+        .spawn((
+            Overlay::new(anchor)
+                .placement(tooltip.placement)
+                .gap(tooltip.gap),
+            TooltipPopup { anchor },
+            Pickable::IGNORE,
+            Style::from(taffy::Style {
+                max_size: taffy::Size {
+                    width: Dimension::length(tooltip_style.max_width),
+                    height: Dimension::auto(),
+                },
+                padding: taffy::Rect {
+                    left: LengthPercentage::length(tooltip_style.padding),
+                    right: LengthPercentage::length(tooltip_style.padding),
+                    top: LengthPercentage::length(0.5 * tooltip_style.padding),
+                    bottom: LengthPercentage::length(0.5 * tooltip_style.padding),
+                },
+                ..Default::default()
+            })
+            .background(tooltip_style.background)
+            .corner_radii(Corners::all(tooltip_style.corner_radius)),
+            children![(
+                Text::new(tooltip.text.clone()),
+                TextFont::from_font_size(tooltip_style.font_size),
+                TextColor(tooltip_style.text_color),
+                Pickable::IGNORE,
+            )],
+        ))
+        .id();
+
+    active.popup = Some(popup);
+}
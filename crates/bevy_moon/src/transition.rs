@@ -0,0 +1,377 @@
+//! Animated transitions of [`Style`] changes.
+
+use core::time::Duration;
+
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_color::{Alpha, Color, Mix};
+use bevy_ecs::{
+    component::Component,
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res},
+};
+use bevy_math::{
+    FloatExt,
+    curve::{Curve, EaseFunction},
+};
+use bevy_time::Time;
+use taffy::{CompactLength, Dimension, LengthPercentage, LengthPercentageAuto};
+
+use crate::style::{BorderColor, BoxShadow, Corners, Outline, Style};
+
+/// Animates the changes of a [`Style`] instead of snapping to them.
+///
+/// Interpolates the background, border color, corner radii, box shadow, outline,
+/// size, inset and opacity. The other fields are applied immediately.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(TransitionState)]
+pub struct Transition {
+    pub duration: Duration,
+    pub delay: Duration,
+    pub easing: EaseFunction,
+}
+
+impl Default for Transition {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+impl Transition {
+    pub const DEFAULT: Self = Self {
+        duration: Duration::from_millis(150),
+        delay: Duration::ZERO,
+        easing: EaseFunction::CubicOut,
+    };
+
+    #[inline]
+    pub const fn new(duration: Duration) -> Self {
+        Self {
+            duration,
+            ..Self::DEFAULT
+        }
+    }
+
+    #[inline]
+    pub const fn delay(mut self, delay: Duration) -> Self {
+        self.delay = delay;
+        self
+    }
+
+    #[inline]
+    pub const fn easing(mut self, easing: EaseFunction) -> Self {
+        self.easing = easing;
+        self
+    }
+
+    /// Returns the eased progress after the elapsed time.
+    fn progress(&self, elapsed: Duration) -> f32 {
+        let Some(elapsed) = elapsed.checked_sub(self.delay) else {
+            return 0.0;
+        };
+
+        if self.duration.is_zero() {
+            return 1.0;
+        }
+
+        let t = elapsed.as_secs_f32() / self.duration.as_secs_f32();
+        self.easing.sample_clamped(t)
+    }
+}
+
+/// The running transition of a node.
+#[derive(Component, Clone, Debug, Default)]
+pub struct TransitionState {
+    from: AnimatableStyle,
+    to: AnimatableStyle,
+    /// The value written into the [`Style`] last time.
+    written: Option<AnimatableStyle>,
+    elapsed: Duration,
+    running: bool,
+}
+
+impl TransitionState {
+    #[inline]
+    pub fn is_running(&self) -> bool {
+        self.running
+    }
+
+    /// The final values of the running transition.
+    #[inline]
+    pub(crate) fn target(&self) -> Option<&AnimatableStyle> {
+        self.running.then_some(&self.to)
+    }
+}
+
+/// The animatable fields of a [`Style`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) struct AnimatableStyle {
+    pub background: Option<Color>,
+    pub border_color: Option<BorderColor>,
+    pub corner_radii: Corners<f32>,
+    pub box_shadow: Option<BoxShadow>,
+    pub outline: Option<Outline>,
+    pub size: taffy::Size<Dimension>,
+    pub inset: taffy::Rect<LengthPercentageAuto>,
+    pub opacity: f32,
+}
+
+// SAFETY: The taffy lengths of the outline, the size and the inset are only thread unsafe with
+// a `calc` value, which moon does not implement, the same as for `Style`.
+unsafe impl Sync for AnimatableStyle {}
+// SAFETY: See the `Sync` impl.
+unsafe impl Send for AnimatableStyle {}
+
+impl Default for AnimatableStyle {
+    fn default() -> Self {
+        Self::from_style(&Style::DEFAULT)
+    }
+}
+
+impl AnimatableStyle {
+    pub fn from_style(style: &Style) -> Self {
+        let inner = style.get_ref();
+        Self {
+            background: style.background,
+            border_color: style.border_color,
+            corner_radii: style.corner_radii,
+            box_shadow: style.box_shadow,
+            outline: style.outline,
+            size: inner.size,
+            inset: inner.inset,
+            opacity: style.opacity,
+        }
+    }
+
+    pub fn apply(&self, style: &mut Style) {
+        style.background = self.background;
+        style.border_color = self.border_color;
+        style.corner_radii = self.corner_radii;
+        style.box_shadow = self.box_shadow;
+        style.outline = self.outline;
+        style.opacity = self.opacity;
+
+        let inner = style.get_mut();
+        inner.size = self.size;
+        inner.inset = self.inset;
+    }
+
+    fn interpolate(&self, other: &Self, t: f32) -> Self {
+        Self {
+            background: mix_option(self.background, other.background, t, mix_color),
+            border_color: mix_option(self.border_color, other.border_color, t, mix_border_color),
+            corner_radii: Corners {
+                bottom_left: self
+                    .corner_radii
+                    .bottom_left
+                    .lerp(other.corner_radii.bottom_left, t),
+                bottom_right: self
+                    .corner_radii
+                    .bottom_right
+                    .lerp(other.corner_radii.bottom_right, t),
+                top_right: self
+                    .corner_radii
+                    .top_right
+                    .lerp(other.corner_radii.top_right, t),
+                top_left: self
+                    .corner_radii
+                    .top_left
+                    .lerp(other.corner_radii.top_left, t),
+            },
+            box_shadow: mix_option(self.box_shadow, other.box_shadow, t, mix_box_shadow),
+            outline: mix_option(self.outline, other.outline, t, mix_outline),
+            size: taffy::Size {
+                width: lerp_length(self.size.width, other.size.width, t),
+                height: lerp_length(self.size.height, other.size.height, t),
+            },
+            inset: taffy::Rect {
+                left: lerp_length(self.inset.left, other.inset.left, t),
+                right: lerp_length(self.inset.right, other.inset.right, t),
+                top: lerp_length(self.inset.top, other.inset.top, t),
+                bottom: lerp_length(self.inset.bottom, other.inset.bottom, t),
+            },
+            opacity: self.opacity.lerp(other.opacity, t),
+        }
+    }
+}
+
+/// A missing value fades from or to the transparent version of the other one.
+fn mix_option<T, F>(from: Option<T>, to: Option<T>, t: f32, mix: F) -> Option<T>
+where
+    T: Copy + Transparent,
+    F: Fn(T, T, f32) -> T,
+{
+    match (from, to) {
+        (Some(from), Some(to)) => Some(mix(from, to, t)),
+        (None, Some(to)) => Some(mix(to.transparent(), to, t)),
+        (Some(from), None) => Some(mix(from, from.transparent(), t)),
+        (None, None) => None,
+    }
+}
+
+trait Transparent {
+    fn transparent(self) -> Self;
+}
+
+impl Transparent for Color {
+    fn transparent(self) -> Self {
+        self.with_alpha(0.0)
+    }
+}
+
+impl Transparent for BorderColor {
+    fn transparent(self) -> Self {
+        Self {
+            left: self.left.transparent(),
+            bottom: self.bottom.transparent(),
+            right: self.right.transparent(),
+            top: self.top.transparent(),
+        }
+    }
+}
+
+impl Transparent for BoxShadow {
+    fn transparent(self) -> Self {
+        Self {
+            color: self.color.transparent(),
+            ..self
+        }
+    }
+}
+
+impl Transparent for Outline {
+    fn transparent(self) -> Self {
+        Self {
+            color: self.color.transparent(),
+            ..self
+        }
+    }
+}
+
+fn mix_color(from: Color, to: Color, t: f32) -> Color {
+    from.mix(&to, t)
+}
+
+fn mix_border_color(from: BorderColor, to: BorderColor, t: f32) -> BorderColor {
+    BorderColor {
+        left: from.left.mix(&to.left, t),
+        bottom: from.bottom.mix(&to.bottom, t),
+        right: from.right.mix(&to.right, t),
+        top: from.top.mix(&to.top, t),
+    }
+}
+
+fn mix_box_shadow(from: BoxShadow, to: BoxShadow, t: f32) -> BoxShadow {
+    BoxShadow {
+        color: from.color.mix(&to.color, t),
+        offset: from.offset.lerp(to.offset, t),
+        blur_radius: from.blur_radius.lerp(to.blur_radius, t),
+        spread_radius: from.spread_radius.lerp(to.spread_radius, t),
+    }
+}
+
+fn mix_outline(from: Outline, to: Outline, t: f32) -> Outline {
+    Outline {
+        color: from.color.mix(&to.color, t),
+        width: lerp_length(from.width, to.width, t),
+        offset: lerp_length(from.offset, to.offset, t),
+    }
+}
+
+trait Length: Copy {
+    fn into_raw(self) -> CompactLength;
+    fn length(value: f32) -> Self;
+    fn percent(value: f32) -> Self;
+}
+
+macro_rules! impl_length {
+    ($($ty:ty),*) => {
+        $(
+            impl Length for $ty {
+                fn into_raw(self) -> CompactLength {
+                    <$ty>::into_raw(self)
+                }
+                fn length(value: f32) -> Self {
+                    <$ty>::length(value)
+                }
+                fn percent(value: f32) -> Self {
+                    <$ty>::percent(value)
+                }
+            }
+        )*
+    };
+}
+
+impl_length!(Dimension, LengthPercentage, LengthPercentageAuto);
+
+/// Lengths and percentages are interpolated with the same unit, the others snap to the target.
+fn lerp_length<T: Length>(from: T, to: T, t: f32) -> T {
+    let (from_raw, to_raw) = (from.into_raw(), to.into_raw());
+
+    match (from_raw.tag(), to_raw.tag()) {
+        (CompactLength::LENGTH_TAG, CompactLength::LENGTH_TAG) => {
+            T::length(from_raw.value().lerp(to_raw.value(), t))
+        }
+        (CompactLength::PERCENT_TAG, CompactLength::PERCENT_TAG) => {
+            T::percent(from_raw.value().lerp(to_raw.value(), t))
+        }
+        _ => to,
+    }
+}
+
+/// A plugin that runs [`Transition`]s.
+pub struct TransitionPlugin;
+
+impl Plugin for TransitionPlugin {
+    fn build(&self, app: &mut App) {
+        let system = transition_system.in_set(AnimationSystems);
+
+        // Animates the style variants too.
+        #[cfg(feature = "picking")]
+        let system = system.after(crate::states::style_states_system);
+
+        app.add_systems(PostUpdate, system);
+    }
+}
+
+pub fn transition_system(
+    time: Res<Time>,
+    mut query: Query<(&Transition, &mut TransitionState, &mut Style)>,
+) {
+    for (transition, mut state, mut style) in query.iter_mut() {
+        let current = AnimatableStyle::from_style(&style);
+
+        let Some(written) = state.written else {
+            // The initial style is not animated.
+            state.written = Some(current);
+            continue;
+        };
+
+        // The style has been changed outside, animates from the displayed values to the new ones.
+        if current != written {
+            state.from = written;
+            state.to = current;
+            state.elapsed = Duration::ZERO;
+            state.running = true;
+        }
+
+        if !state.running {
+            continue;
+        }
+
+        state.elapsed += time.delta();
+
+        let t = transition.progress(state.elapsed);
+        let value = if t >= 1.0 && state.elapsed >= transition.delay + transition.duration {
+            state.running = false;
+            state.to
+        } else {
+            state.from.interpolate(&state.to, t)
+        };
+
+        state.written = Some(value);
+
+        if value != current {
+            value.apply(&mut style);
+        }
+    }
+}
@@ -1,4 +1,6 @@
+use bevy_camera::Camera;
 use bevy_ecs::entity::Entity;
+use bevy_math::{Rect, Vec2};
 use bevy_transform::components::GlobalTransform;
 
 /// Sort entities by their z-order.
@@ -28,3 +30,29 @@ const fn sort_entities_by_z_order_with<const BTF: bool>(z: f32, index: u32) -> (
         (-z, u32::MAX - index)
     }
 }
+
+/// Projects the rect of a node into the viewport of the camera, in logical pixels.
+pub fn project_node_rect(
+    camera: &Camera,
+    camera_transform: &GlobalTransform,
+    transform: &GlobalTransform,
+    size: Vec2,
+) -> Option<Rect> {
+    let half_size = 0.5 * size;
+    let mut rect = Rect::EMPTY;
+
+    for corner in [
+        Vec2::new(-half_size.x, -half_size.y),
+        Vec2::new(half_size.x, -half_size.y),
+        Vec2::new(half_size.x, half_size.y),
+        Vec2::new(-half_size.x, half_size.y),
+    ] {
+        let world_point = transform.transform_point(corner.extend(0.0));
+        let point = camera
+            .world_to_viewport(camera_transform, world_point)
+            .ok()?;
+        rect = rect.union_point(point);
+    }
+
+    Some(rect)
+}
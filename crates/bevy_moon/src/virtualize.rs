@@ -0,0 +1,561 @@
+//! Virtualized lists and grids, which only spawn the items inside their scroll viewport.
+//!
+//! A [`VirtualList`] or [`VirtualGrid`] is a scrolled node with a single content child, sized for all
+//! the items. The items are absolutely positioned in the content, the ones scrolled out of the
+//! viewport are hidden and bound again to other indices. Fill an item in a [`VirtualItemBind`] observer.
+
+use core::ops::Range;
+
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::ChildOf,
+    query::Without,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+    world::Ref,
+};
+use bevy_math::Vec2;
+use taffy::{Dimension, Display, LengthPercentageAuto, Position};
+
+use crate::{
+    computed::ComputedNode,
+    elements::node::{Node, ScrollPosition},
+    style::Style,
+};
+
+/// The height of the rows of a [`VirtualList`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum RowHeight {
+    /// All the rows have the height.
+    Fixed(f32),
+    /// The rows are laid out by their content, their heights are read from the layout once they
+    /// were shown, and read again after the list is changed.
+    ///
+    /// The rows which were never shown use the estimate.
+    Measured { estimate: f32 },
+}
+
+impl Default for RowHeight {
+    fn default() -> Self {
+        Self::Fixed(24.0)
+    }
+}
+
+/// A vertical list of `len` rows, only the rows in the viewport and the overscan are spawned.
+///
+/// The list is scrolled by its [`ScrollPosition`], set its overflow to `Scroll` for the mouse wheel.
+/// Changing the list binds the shown rows again, e.g. after the data changed.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[require(Node, ScrollPosition, VirtualItems)]
+pub struct VirtualList {
+    pub len: usize,
+    pub row_height: RowHeight,
+    /// The rows spawned above and below the viewport.
+    pub overscan: usize,
+}
+
+impl VirtualList {
+    #[inline]
+    pub const fn new(len: usize, row_height: RowHeight) -> Self {
+        Self {
+            len,
+            row_height,
+            overscan: 4,
+        }
+    }
+
+    #[inline]
+    pub const fn fixed(len: usize, height: f32) -> Self {
+        Self::new(len, RowHeight::Fixed(height))
+    }
+
+    #[inline]
+    pub const fn measured(len: usize, estimate: f32) -> Self {
+        Self::new(len, RowHeight::Measured { estimate })
+    }
+
+    #[inline]
+    pub const fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+}
+
+/// A grid of `len` cells of a fixed size, filling the rows from left to right.
+///
+/// The columns fit the width of the grid, only the rows in the viewport and the overscan are spawned.
+/// Changing the grid binds the shown cells again, e.g. after the data changed.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+#[require(Node, ScrollPosition, VirtualItems)]
+pub struct VirtualGrid {
+    pub len: usize,
+    pub cell_size: Vec2,
+    /// The rows spawned above and below the viewport.
+    pub overscan: usize,
+}
+
+impl VirtualGrid {
+    #[inline]
+    pub const fn new(len: usize, cell_size: Vec2) -> Self {
+        Self {
+            len,
+            cell_size,
+            overscan: 2,
+        }
+    }
+
+    #[inline]
+    pub const fn overscan(mut self, overscan: usize) -> Self {
+        self.overscan = overscan;
+        self
+    }
+
+    /// Returns the number of columns in the width.
+    #[inline]
+    pub fn columns(&self, width: f32) -> usize {
+        if self.cell_size.x > 0.0 {
+            ((width / self.cell_size.x) as usize).max(1)
+        } else {
+            1
+        }
+    }
+}
+
+/// An item of a [`VirtualList`] or [`VirtualGrid`], bound to the index.
+///
+/// The hidden items have a `display` of none, set another `display` when it's bound.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Eq)]
+#[require(Node)]
+pub struct VirtualItem {
+    pub list: Entity,
+    pub index: usize,
+}
+
+/// Triggered on an item when it's spawned or recycled for an index, or when its list is changed.
+///
+/// The item keeps the children and components of its previous index, update them here.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct VirtualItemBind {
+    pub entity: Entity,
+    pub list: Entity,
+    pub index: usize,
+}
+
+/// The spawned items of a [`VirtualList`] or [`VirtualGrid`].
+#[derive(Component, Clone, Debug, Default)]
+pub struct VirtualItems {
+    content: Option<Entity>,
+    /// The shown items, sorted by index.
+    items: Vec<(usize, Entity)>,
+    /// The hidden items, ready to be recycled.
+    free: Vec<Entity>,
+    /// The measured heights of the rows.
+    heights: Vec<Option<f32>>,
+    /// The offsets of the measured rows from the top, and the height of all the rows at the end.
+    offsets: Vec<f32>,
+    range: Range<usize>,
+}
+
+impl VirtualItems {
+    /// Returns the node sized for all the items, the parent of the items.
+    #[inline]
+    pub fn content(&self) -> Option<Entity> {
+        self.content
+    }
+
+    /// Returns the indices of the shown items.
+    #[inline]
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+
+    /// Returns the item bound to the index, if it's shown.
+    pub fn get(&self, index: usize) -> Option<Entity> {
+        self.items
+            .binary_search_by_key(&index, |(index, _)| *index)
+            .ok()
+            .map(|i| self.items[i].1)
+    }
+
+    /// Returns the shown items with their indices.
+    #[inline]
+    pub fn iter(&self) -> impl Iterator<Item = (usize, Entity)> + '_ {
+        self.items.iter().copied()
+    }
+}
+
+/// A plugin that adds [`VirtualList`]s and [`VirtualGrid`]s.
+pub struct VirtualizePlugin;
+
+impl Plugin for VirtualizePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            (virtual_list_system, virtual_grid_system).before(AnimationSystems),
+        );
+    }
+}
+
+/// The placement of an item in the content, from its top left corner.
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct ItemPlacement {
+    position: Vec2,
+    width: Option<f32>,
+    height: Option<f32>,
+}
+
+impl ItemPlacement {
+    fn node(&self) -> taffy::Style {
+        let length = |value: Option<f32>| value.map_or(Dimension::auto(), Dimension::length);
+
+        taffy::Style {
+            position: Position::Absolute,
+            inset: taffy::Rect {
+                left: LengthPercentageAuto::length(self.position.x),
+                // Rows without a width span the content.
+                right: if self.width.is_some() {
+                    LengthPercentageAuto::auto()
+                } else {
+                    LengthPercentageAuto::length(0.0)
+                },
+                top: LengthPercentageAuto::length(self.position.y),
+                bottom: LengthPercentageAuto::auto(),
+            },
+            size: taffy::Size {
+                width: length(self.width),
+                height: length(self.height),
+            },
+            ..Default::default()
+        }
+    }
+}
+
+/// Returns the content of the list, spawns it on the first call.
+fn content_entity(commands: &mut Commands, list: Entity, items: &mut VirtualItems) -> Entity {
+    *items.content.get_or_insert_with(|| {
+        commands
+            .spawn((
+                Node,
+                ChildOf(list),
+                Style::from(taffy::Style {
+                    flex_shrink: 0.0,
+                    size: taffy::Size {
+                        width: Dimension::percent(1.0),
+                        height: Dimension::length(0.0),
+                    },
+                    ..Default::default()
+                }),
+            ))
+            .id()
+    })
+}
+
+/// Shows the items in the range and hides the others, binds the items of new indices.
+fn update_items(
+    commands: &mut Commands,
+    list: Entity,
+    items: &mut VirtualItems,
+    range: Range<usize>,
+    rebind: bool,
+    item_query: &mut Query<(&mut VirtualItem, &mut Style, &ComputedNode)>,
+    place: impl Fn(usize) -> ItemPlacement,
+) {
+    let content = content_entity(commands, list, items);
+
+    // Releases the items scrolled out, and the despawned ones.
+    let mut kept = Vec::with_capacity(range.len());
+    for (index, entity) in items.items.drain(..) {
+        let Ok((_, mut style, _)) = item_query.get_mut(entity) else {
+            continue;
+        };
+
+        if range.contains(&index) {
+            kept.push((index, entity));
+        } else {
+            style.get_mut().display = Display::None;
+            items.free.push(entity);
+        }
+    }
+
+    let mut kept = kept.into_iter().peekable();
+    for index in range.clone() {
+        let node = place(index).node();
+
+        if let Some((_, entity)) = kept.next_if(|(kept, _)| *kept == index) {
+            items.items.push((index, entity));
+
+            if let Ok((_, mut style, _)) = item_query.get_mut(entity) {
+                set_item_node(&mut style, &node);
+            }
+            if rebind {
+                commands.trigger(VirtualItemBind {
+                    entity,
+                    list,
+                    index,
+                });
+            }
+            continue;
+        }
+
+        let recycled =
+            core::iter::from_fn(|| items.free.pop()).find(|&entity| item_query.contains(entity));
+
+        let entity = match recycled {
+            Some(entity) => {
+                if let Ok((mut item, mut style, _)) = item_query.get_mut(entity) {
+                    item.index = index;
+                    set_item_node(&mut style, &node);
+                }
+                entity
+            }
+            None => commands
+                .spawn((
+                    VirtualItem { list, index },
+                    ChildOf(content),
+                    Style::from(node),
+                ))
+                .id(),
+        };
+
+        items.items.push((index, entity));
+        commands.trigger(VirtualItemBind {
+            entity,
+            list,
+            index,
+        });
+    }
+
+    items.range = range;
+}
+
+/// Places an item, only writes the style if the placement changed.
+///
+/// A hidden item is shown as flex, otherwise its `display` is kept.
+fn set_item_node(style: &mut Style, node: &taffy::Style) {
+    if style.get_ref().display == Display::None {
+        style.get_mut().display = Display::Flex;
+    }
+
+    let inner = style.get_ref();
+    if inner.position != node.position || inner.inset != node.inset || inner.size != node.size {
+        let inner = style.get_mut();
+        inner.position = node.position;
+        inner.inset = node.inset;
+        inner.size = node.size;
+    }
+}
+
+/// Sets the height of the content of the list.
+fn set_content_height(
+    style_query: &mut Query<&mut Style, Without<VirtualItem>>,
+    content: Entity,
+    height: f32,
+) {
+    let Ok(mut style) = style_query.get_mut(content) else {
+        return;
+    };
+
+    let height = Dimension::length(height);
+    if style.get_ref().size.height != height {
+        style.get_mut().size.height = height;
+    }
+}
+
+/// Reads the heights of the shown rows from the layout of the last frame, and updates the offsets
+/// of the rows from the first changed one. Returns whether the offsets changed.
+///
+/// The heights are cleared when the list is changed, as the rows are bound again.
+fn update_row_offsets(
+    items: &mut VirtualItems,
+    len: usize,
+    estimate: f32,
+    rebind: bool,
+    item_query: &Query<(&mut VirtualItem, &mut Style, &ComputedNode)>,
+) -> bool {
+    let mut changed_from = (rebind || items.offsets.len() != len + 1).then_some(0);
+
+    if rebind {
+        items.heights.clear();
+    }
+    items.heights.resize(len, None);
+
+    // The shown rows were laid out for their index in the last frame.
+    if !rebind {
+        for &(index, item) in &items.items {
+            if let Some(height) = items.heights.get_mut(index)
+                && let Ok((.., computed_item)) = item_query.get(item)
+                && computed_item.size.y > 0.0
+                && *height != Some(computed_item.size.y)
+            {
+                *height = Some(computed_item.size.y);
+                changed_from = Some(changed_from.map_or(index, |from| from.min(index)));
+            }
+        }
+    }
+
+    let Some(from) = changed_from else {
+        return false;
+    };
+
+    items.offsets.resize(len + 1, 0.0);
+    for index in from..len {
+        items.offsets[index + 1] =
+            items.offsets[index] + items.heights[index].unwrap_or(estimate).max(0.0);
+    }
+
+    true
+}
+
+type VirtualListData = (
+    Entity,
+    Ref<'static, VirtualList>,
+    &'static mut VirtualItems,
+    Ref<'static, ScrollPosition>,
+    Ref<'static, ComputedNode>,
+);
+
+/// Spawns the rows of the lists in their viewports, from the layout of the last frame.
+pub fn virtual_list_system(
+    mut list_query: Query<VirtualListData>,
+    mut item_query: Query<(&mut VirtualItem, &mut Style, &ComputedNode)>,
+    mut style_query: Query<&mut Style, Without<VirtualItem>>,
+    mut commands: Commands,
+) {
+    for (entity, list, mut items, scroll_position, computed_node) in list_query.iter_mut() {
+        let items = items.as_mut();
+
+        let offsets_changed = match list.row_height {
+            RowHeight::Fixed(_) => false,
+            RowHeight::Measured { estimate } => {
+                update_row_offsets(items, list.len, estimate, list.is_changed(), &item_query)
+            }
+        };
+
+        // The rows are only placed again when the list, its viewport or the measured rows changed.
+        if items.content.is_some()
+            && !offsets_changed
+            && !list.is_changed()
+            && !scroll_position.is_changed()
+            && !computed_node.is_changed()
+        {
+            continue;
+        }
+
+        let top = scroll_position.0.y;
+        let bottom = top + computed_node.content_box_size.y;
+
+        let fixed = match list.row_height {
+            RowHeight::Fixed(height) => Some(height.max(1.0)),
+            RowHeight::Measured { .. } => None,
+        };
+
+        let offsets = core::mem::take(&mut items.offsets);
+        let range = match fixed {
+            Some(height) => {
+                let first = (top / height).floor().max(0.0) as usize;
+                let last = (bottom / height).ceil().max(0.0) as usize;
+
+                first..last
+            }
+            None => {
+                // The rows starting above the bottom, and ending below the top.
+                let first = offsets
+                    .partition_point(|offset| *offset <= top)
+                    .saturating_sub(1);
+                let last = offsets.partition_point(|offset| *offset < bottom);
+
+                first..last
+            }
+        };
+
+        let range = range.start.saturating_sub(list.overscan).min(list.len)
+            ..range.end.saturating_add(list.overscan).min(list.len);
+
+        let place = |index: usize| match fixed {
+            Some(height) => ItemPlacement {
+                position: Vec2::new(0.0, index as f32 * height),
+                width: None,
+                height: Some(height),
+            },
+            None => ItemPlacement {
+                position: Vec2::new(0.0, offsets[index]),
+                width: None,
+                height: None,
+            },
+        };
+
+        let height = match fixed {
+            Some(height) => list.len as f32 * height,
+            None => offsets.last().copied().unwrap_or_default(),
+        };
+
+        update_items(
+            &mut commands,
+            entity,
+            items,
+            range,
+            list.is_changed(),
+            &mut item_query,
+            place,
+        );
+        items.offsets = offsets;
+
+        if let Some(content) = items.content {
+            set_content_height(&mut style_query, content, height);
+        }
+    }
+}
+
+/// Spawns the cells of the grids in their viewports, from the layout of the last frame.
+pub fn virtual_grid_system(
+    mut grid_query: Query<(
+        Entity,
+        Ref<VirtualGrid>,
+        &mut VirtualItems,
+        &ScrollPosition,
+        &ComputedNode,
+    )>,
+    mut item_query: Query<(&mut VirtualItem, &mut Style, &ComputedNode)>,
+    mut style_query: Query<&mut Style, Without<VirtualItem>>,
+    mut commands: Commands,
+) {
+    for (entity, grid, mut items, scroll_position, computed_node) in grid_query.iter_mut() {
+        let cell_size = grid.cell_size.max(Vec2::ONE);
+        let columns = grid.columns(computed_node.content_box_size.x);
+        let rows = grid.len.div_ceil(columns);
+
+        let top = scroll_position.0.y;
+        let bottom = top + computed_node.content_box_size.y;
+        let first = ((top / cell_size.y).floor().max(0.0) as usize).saturating_sub(grid.overscan);
+        let last = ((bottom / cell_size.y).ceil().max(0.0) as usize)
+            .saturating_add(grid.overscan)
+            .min(rows);
+
+        let range = (first * columns).min(grid.len)..(last * columns).min(grid.len);
+
+        update_items(
+            &mut commands,
+            entity,
+            items.as_mut(),
+            range,
+            grid.is_changed(),
+            &mut item_query,
+            |index| ItemPlacement {
+                position: Vec2::new(
+                    (index % columns) as f32 * cell_size.x,
+                    (index / columns) as f32 * cell_size.y,
+                ),
+                width: Some(cell_size.x),
+                height: Some(cell_size.y),
+            },
+        );
+
+        if let Some(content) = items.content {
+            set_content_height(&mut style_query, content, rows as f32 * cell_size.y);
+        }
+    }
+}
@@ -0,0 +1,16 @@
+//! Buttons.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_ecs::{component::Component, reflect::ReflectComponent};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+
+use super::{Activatable, accessible_node};
+
+/// A button, triggers an [`Activate`](super::Activate) when clicked.
+///
+/// The label is the text of its descendants.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Activatable, AccessibilityNode = accessible_node(Role::Button))]
+pub struct Button;
@@ -0,0 +1,138 @@
+//! Checkboxes and toggle switches.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    observer::On,
+    query::Changed,
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use taffy::JustifyContent;
+
+use crate::style::Style;
+
+use super::{Activatable, Activate, ValueChange, accessible_node, set_checked};
+
+/// A checkbox, flips `checked` when activated and triggers a [`ValueChange<bool>`].
+///
+/// The node is marked [`Checked`](crate::interaction::Checked) while checked.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Activatable, AccessibilityNode = accessible_node(Role::CheckBox))]
+pub struct Checkbox {
+    pub checked: bool,
+}
+
+impl Checkbox {
+    #[inline]
+    pub const fn new(checked: bool) -> Self {
+        Self { checked }
+    }
+}
+
+/// A toggle switch, flips `checked` when activated and triggers a [`ValueChange<bool>`].
+///
+/// The children are justified to the end while checked, which slides a knob child across the track.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Activatable, AccessibilityNode = accessible_node(Role::Switch))]
+pub struct Toggle {
+    pub checked: bool,
+}
+
+impl Toggle {
+    #[inline]
+    pub const fn new(checked: bool) -> Self {
+        Self { checked }
+    }
+}
+
+/// A plugin that adds [`Checkbox`]es and [`Toggle`]s.
+pub struct CheckboxPlugin;
+
+impl Plugin for CheckboxPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_checkbox_activate)
+            .add_observer(on_toggle_activate)
+            .add_systems(
+                PostUpdate,
+                (checkbox_system, toggle_system).before(AnimationSystems),
+            );
+    }
+}
+
+fn on_checkbox_activate(
+    event: On<Activate>,
+    mut query: Query<&mut Checkbox>,
+    mut commands: Commands,
+) {
+    let Ok(mut checkbox) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    checkbox.checked = !checkbox.checked;
+
+    commands.trigger(ValueChange {
+        entity: event.entity,
+        value: checkbox.checked,
+    });
+}
+
+fn on_toggle_activate(event: On<Activate>, mut query: Query<&mut Toggle>, mut commands: Commands) {
+    let Ok(mut toggle) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    toggle.checked = !toggle.checked;
+
+    commands.trigger(ValueChange {
+        entity: event.entity,
+        value: toggle.checked,
+    });
+}
+
+pub fn checkbox_system(
+    mut query: Query<(Entity, &Checkbox, Option<&mut AccessibilityNode>), Changed<Checkbox>>,
+    mut commands: Commands,
+) {
+    for (entity, checkbox, accessible) in query.iter_mut() {
+        set_checked(
+            &mut commands,
+            entity,
+            checkbox.checked,
+            accessible.map(|accessible| accessible.into_inner()),
+        );
+    }
+}
+
+pub fn toggle_system(
+    mut query: Query<
+        (Entity, &Toggle, &mut Style, Option<&mut AccessibilityNode>),
+        Changed<Toggle>,
+    >,
+    mut commands: Commands,
+) {
+    for (entity, toggle, mut style, accessible) in query.iter_mut() {
+        set_checked(
+            &mut commands,
+            entity,
+            toggle.checked,
+            accessible.map(|accessible| accessible.into_inner()),
+        );
+
+        let justify_content = Some(if toggle.checked {
+            JustifyContent::FlexEnd
+        } else {
+            JustifyContent::FlexStart
+        });
+        if style.get_ref().justify_content != justify_content {
+            style.get_mut().justify_content = justify_content;
+        }
+    }
+}
@@ -0,0 +1,820 @@
+//! Color pickers with a saturation-value field, a hue bar, an alpha bar and a hex input.
+//!
+//! The gradients are stretched textures, tinted and layered over the background of the nodes,
+//! so they stay smooth at any zoom.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate, Update};
+use bevy_asset::{Assets, Handle, RenderAssetUsages};
+use bevy_camera::Camera;
+use bevy_color::{Alpha, Color, ColorToPacked, Hsva, Hue, Srgba};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    lifecycle::Add,
+    observer::On,
+    query::Changed,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res},
+    world::{FromWorld, World},
+};
+use bevy_image::{Image, ImageSampler};
+use bevy_input::{
+    ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{FocusedInput, InputFocus, tab_navigation::TabIndex};
+use bevy_math::Vec2;
+use bevy_picking::{
+    Pickable,
+    events::{Drag, Pointer, Press},
+    pointer::PointerButton,
+};
+use bevy_render::render_resource::{Extent3d, TextureDimension, TextureFormat};
+use bevy_text::{TextColor, TextFont};
+use bevy_transform::components::GlobalTransform;
+use taffy::{
+    AlignItems, Dimension, FlexDirection, LengthPercentage, LengthPercentageAuto, Position,
+};
+
+use crate::{
+    computed::ComputedNode,
+    elements::{
+        image::{ImageNode, NodeImageMode},
+        node::Node,
+        text::Text,
+    },
+    interaction::Interaction,
+    picking::pointer_local_point,
+    style::{BorderColor, Corners, Style},
+};
+
+use super::ValueChange;
+
+/// The texels of the gradient textures along their length.
+const GRADIENT_LENGTH: u32 = 256;
+
+/// A color picker, builds its parts as children when added.
+///
+/// Triggers a [`ValueChange<Color>`] when the color is changed by the user.
+/// The color is kept in HSV, so the hue survives a zero saturation or value.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Node, AccessibilityNode = AccessibilityNode::from(accesskit::Node::new(Role::ColorWell)))]
+pub struct ColorPicker {
+    pub color: Hsva,
+    /// Shows the alpha bar, otherwise the alpha is kept.
+    pub alpha: bool,
+}
+
+impl Default for ColorPicker {
+    fn default() -> Self {
+        Self::new(Color::WHITE)
+    }
+}
+
+impl ColorPicker {
+    #[inline]
+    pub fn new(color: impl Into<Color>) -> Self {
+        Self {
+            color: Hsva::from(color.into()),
+            alpha: true,
+        }
+    }
+
+    #[inline]
+    pub const fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    #[inline]
+    pub fn color(&self) -> Color {
+        Color::from(self.color)
+    }
+
+    /// Returns the color in `#RRGGBB` notation, or `#RRGGBBAA` if it's translucent.
+    #[inline]
+    pub fn hex(&self) -> String {
+        Srgba::from(self.color).to_hex()
+    }
+}
+
+/// The parts of a [`ColorPicker`] which are dragged.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ColorPickerArea {
+    /// Saturation from left to right, value from bottom to top.
+    SaturationValue,
+    Hue,
+    Alpha,
+}
+
+/// A dragged part of a [`ColorPicker`].
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Node, ColorAreaDrag)]
+pub struct ColorPickerPart {
+    pub picker: Entity,
+    pub area: ColorPickerArea,
+}
+
+/// The pointer dragging a [`ColorPickerPart`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct ColorAreaDrag {
+    /// The camera of the current drag.
+    camera: Option<Entity>,
+}
+
+/// The hex input of a [`ColorPicker`], typed while focused.
+///
+/// `Enter` or losing the focus applies the typed color, `Escape` cancels it.
+#[derive(Component, Clone, Debug)]
+#[require(Text, Interaction, TabIndex)]
+pub struct ColorHexInput {
+    pub picker: Entity,
+    /// The typed text, `None` unless editing.
+    editing: Option<String>,
+}
+
+impl ColorHexInput {
+    #[inline]
+    pub const fn new(picker: Entity) -> Self {
+        Self {
+            picker,
+            editing: None,
+        }
+    }
+
+    /// Returns `true` while a color is typed.
+    #[inline]
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+/// The child entities of a [`ColorPicker`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct ColorPickerParts {
+    pub field: Entity,
+    pub field_thumb: Entity,
+    pub hue_thumb: Entity,
+    pub alpha_bar: Entity,
+    pub alpha_image: Entity,
+    pub alpha_thumb: Entity,
+    pub hex: Entity,
+    pub preview: Entity,
+}
+
+/// The look of the color pickers.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ColorPickerStyle {
+    pub field_height: f32,
+    pub bar_height: f32,
+    pub gap: f32,
+    /// The diameter of the saturation-value thumb.
+    pub thumb_size: f32,
+    /// The width of the bar thumbs.
+    pub bar_thumb_width: f32,
+    pub thumb_color: Color,
+    pub border_color: Color,
+    /// Shown behind the translucent part of the alpha bar.
+    pub alpha_background: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub corner_radius: f32,
+}
+
+impl Default for ColorPickerStyle {
+    fn default() -> Self {
+        Self {
+            field_height: 160.0,
+            bar_height: 12.0,
+            gap: 8.0,
+            thumb_size: 12.0,
+            bar_thumb_width: 4.0,
+            thumb_color: Color::WHITE,
+            border_color: Color::srgb(0.28, 0.28, 0.31),
+            alpha_background: Color::srgb(0.6, 0.6, 0.6),
+            text_color: Color::srgb(0.92, 0.92, 0.92),
+            font_size: 13.0,
+            corner_radius: 3.0,
+        }
+    }
+}
+
+/// The gradient textures of the color pickers.
+#[derive(Resource, Clone, Debug)]
+pub struct ColorPickerTextures {
+    /// White, fading out from left to right.
+    pub saturation: Handle<Image>,
+    /// Black, fading in from top to bottom.
+    pub value: Handle<Image>,
+    /// The hues from left to right.
+    pub hue: Handle<Image>,
+    /// White, fading in from left to right.
+    pub alpha: Handle<Image>,
+}
+
+impl FromWorld for ColorPickerTextures {
+    fn from_world(world: &mut World) -> Self {
+        let mut images = world.resource_mut::<Assets<Image>>();
+
+        let last = (GRADIENT_LENGTH - 1) as f32;
+        let mut gradient = |vertical: bool, texel: &dyn Fn(f32) -> Srgba| {
+            let data = (0..GRADIENT_LENGTH)
+                .flat_map(|i| texel(i as f32 / last).to_u8_array())
+                .collect();
+            let size = if vertical {
+                Extent3d {
+                    width: 1,
+                    height: GRADIENT_LENGTH,
+                    depth_or_array_layers: 1,
+                }
+            } else {
+                Extent3d {
+                    width: GRADIENT_LENGTH,
+                    height: 1,
+                    depth_or_array_layers: 1,
+                }
+            };
+
+            let mut image = Image::new(
+                size,
+                TextureDimension::D2,
+                data,
+                TextureFormat::Rgba8UnormSrgb,
+                RenderAssetUsages::RENDER_WORLD,
+            );
+            image.sampler = ImageSampler::linear();
+            images.add(image)
+        };
+
+        Self {
+            saturation: gradient(false, &|t| Srgba::WHITE.with_alpha(1.0 - t)),
+            value: gradient(true, &|t| Srgba::BLACK.with_alpha(t)),
+            hue: gradient(false, &|t| Srgba::from(Hsva::hsv(t * 360.0, 1.0, 1.0))),
+            alpha: gradient(false, &|t| Srgba::WHITE.with_alpha(t)),
+        }
+    }
+}
+
+/// A plugin that adds [`ColorPicker`]s.
+pub struct ColorPickerPlugin;
+
+impl Plugin for ColorPickerPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ColorPickerStyle>()
+            .add_observer(on_add_color_picker)
+            .add_observer(on_color_area_press)
+            .add_observer(on_color_area_drag)
+            .add_observer(on_hex_key)
+            .add_systems(Update, hex_blur_system)
+            .add_systems(
+                PostUpdate,
+                (color_picker_system, hex_input_system).before(AnimationSystems),
+            );
+    }
+
+    fn finish(&self, app: &mut App) {
+        // The image assets are ready after all plugins are built.
+        app.init_resource::<ColorPickerTextures>();
+    }
+}
+
+/// Sets the color of a picker, triggers a [`ValueChange<Color>`] if it's changed.
+fn change_color(commands: &mut Commands, entity: Entity, picker: &mut ColorPicker, color: Hsva) {
+    if picker.color == color {
+        return;
+    }
+
+    picker.color = color;
+
+    commands.trigger(ValueChange {
+        entity,
+        value: Color::from(color),
+    });
+}
+
+/// A node stretched over its parent.
+fn fill_style() -> Style {
+    Style::from(taffy::Style {
+        position: Position::Absolute,
+        inset: taffy::Rect {
+            left: LengthPercentageAuto::length(0.0),
+            right: LengthPercentageAuto::length(0.0),
+            top: LengthPercentageAuto::length(0.0),
+            bottom: LengthPercentageAuto::length(0.0),
+        },
+        ..Default::default()
+    })
+}
+
+fn bar_style(style: &ColorPickerStyle) -> Style {
+    Style::from(taffy::Style {
+        size: taffy::Size {
+            width: Dimension::percent(1.0),
+            height: Dimension::length(style.bar_height),
+        },
+        ..Default::default()
+    })
+    .border_color(BorderColor::all(style.border_color))
+    .corner_radii(Corners::all(style.corner_radius))
+}
+
+fn bar_thumb_style(style: &ColorPickerStyle) -> Style {
+    let half = 0.5 * style.bar_thumb_width;
+
+    Style::from(taffy::Style {
+        position: Position::Absolute,
+        size: taffy::Size {
+            width: Dimension::length(style.bar_thumb_width),
+            height: Dimension::auto(),
+        },
+        inset: taffy::Rect {
+            left: LengthPercentageAuto::percent(0.0),
+            right: LengthPercentageAuto::auto(),
+            top: LengthPercentageAuto::length(-2.0),
+            bottom: LengthPercentageAuto::length(-2.0),
+        },
+        margin: taffy::Rect {
+            left: LengthPercentageAuto::length(-half),
+            right: LengthPercentageAuto::length(0.0),
+            top: LengthPercentageAuto::length(0.0),
+            bottom: LengthPercentageAuto::length(0.0),
+        },
+        border: taffy::Rect::length(1.0),
+        ..Default::default()
+    })
+    .background(style.thumb_color)
+    .border_color(BorderColor::all(style.border_color))
+    .corner_radii(Corners::all(half))
+}
+
+fn on_add_color_picker(
+    event: On<Add, ColorPicker>,
+    style: Res<ColorPickerStyle>,
+    textures: Res<ColorPickerTextures>,
+    mut picker_query: Query<(&ColorPicker, &mut Style)>,
+    mut commands: Commands,
+) {
+    let picker = event.entity;
+    let Ok((color_picker, mut picker_style)) = picker_query.get_mut(picker) else {
+        return;
+    };
+
+    let inner = picker_style.get_mut();
+    inner.flex_direction = FlexDirection::Column;
+    inner.gap = taffy::Size {
+        width: LengthPercentage::length(style.gap),
+        height: LengthPercentage::length(style.gap),
+    };
+
+    let image = |handle: &Handle<Image>| {
+        (
+            ImageNode::new(handle.clone()).with_mode(NodeImageMode::Stretch),
+            Pickable::IGNORE,
+            fill_style(),
+        )
+    };
+
+    // The field is the hue, whitened by the saturation and darkened by the value.
+    let field = commands
+        .spawn((
+            ColorPickerPart {
+                picker,
+                area: ColorPickerArea::SaturationValue,
+            },
+            ChildOf(picker),
+            Style::from(taffy::Style {
+                size: taffy::Size {
+                    width: Dimension::percent(1.0),
+                    height: Dimension::length(style.field_height),
+                },
+                flex_shrink: 0.0,
+                ..Default::default()
+            })
+            .corner_radii(Corners::all(style.corner_radius)),
+        ))
+        .id();
+    commands.spawn((image(&textures.saturation), ChildOf(field)));
+    commands.spawn((image(&textures.value), ChildOf(field)));
+
+    let half = 0.5 * style.thumb_size;
+    let field_thumb = commands
+        .spawn((
+            Node,
+            ChildOf(field),
+            Pickable::IGNORE,
+            Style::from(taffy::Style {
+                position: Position::Absolute,
+                size: taffy::Size::from_lengths(style.thumb_size, style.thumb_size),
+                margin: taffy::Rect {
+                    left: LengthPercentageAuto::length(-half),
+                    right: LengthPercentageAuto::length(0.0),
+                    top: LengthPercentageAuto::length(-half),
+                    bottom: LengthPercentageAuto::length(0.0),
+                },
+                border: taffy::Rect::length(2.0),
+                ..Default::default()
+            })
+            .border_color(BorderColor::all(style.thumb_color))
+            .corner_radii(Corners::all(half)),
+        ))
+        .id();
+
+    let hue_bar = commands
+        .spawn((
+            ColorPickerPart {
+                picker,
+                area: ColorPickerArea::Hue,
+            },
+            ChildOf(picker),
+            bar_style(&style),
+        ))
+        .id();
+    commands.spawn((image(&textures.hue), ChildOf(hue_bar)));
+    let hue_thumb = commands
+        .spawn((
+            Node,
+            ChildOf(hue_bar),
+            Pickable::IGNORE,
+            bar_thumb_style(&style),
+        ))
+        .id();
+
+    let mut alpha_bar_style = bar_style(&style).background(style.alpha_background);
+    if !color_picker.alpha {
+        alpha_bar_style.get_mut().display = taffy::Display::None;
+    }
+    let alpha_bar = commands
+        .spawn((
+            ColorPickerPart {
+                picker,
+                area: ColorPickerArea::Alpha,
+            },
+            ChildOf(picker),
+            alpha_bar_style,
+        ))
+        .id();
+    let alpha_image = commands
+        .spawn((image(&textures.alpha), ChildOf(alpha_bar)))
+        .id();
+    let alpha_thumb = commands
+        .spawn((
+            Node,
+            ChildOf(alpha_bar),
+            Pickable::IGNORE,
+            bar_thumb_style(&style),
+        ))
+        .id();
+
+    let row = commands
+        .spawn((
+            Node,
+            ChildOf(picker),
+            Style::from(taffy::Style {
+                flex_direction: FlexDirection::Row,
+                align_items: Some(AlignItems::Center),
+                gap: taffy::Size {
+                    width: LengthPercentage::length(style.gap),
+                    height: LengthPercentage::length(0.0),
+                },
+                ..Default::default()
+            }),
+        ))
+        .id();
+
+    let mut hex_accessible = accesskit::Node::new(Role::TextInput);
+    hex_accessible.set_label("Hex");
+    let hex = commands
+        .spawn((
+            ColorHexInput::new(picker),
+            ChildOf(row),
+            TextFont::from_font_size(style.font_size),
+            TextColor(style.text_color),
+            AccessibilityNode::from(hex_accessible),
+            Style::from(taffy::Style {
+                flex_grow: 1.0,
+                ..Default::default()
+            }),
+        ))
+        .id();
+
+    let preview = commands
+        .spawn((
+            Node,
+            ChildOf(row),
+            Style::from(taffy::Style {
+                size: taffy::Size::from_lengths(2.0 * style.bar_height, style.bar_height + 4.0),
+                border: taffy::Rect::length(1.0),
+                ..Default::default()
+            })
+            .border_color(BorderColor::all(style.border_color))
+            .corner_radii(Corners::all(style.corner_radius)),
+        ))
+        .id();
+
+    commands.entity(picker).insert(ColorPickerParts {
+        field,
+        field_thumb,
+        hue_thumb,
+        alpha_bar,
+        alpha_image,
+        alpha_thumb,
+        hex,
+        preview,
+    });
+}
+
+/// Returns the color at the node-local point of an area, from the center of the node.
+fn color_at(color: Hsva, area: ColorPickerArea, local_point: Vec2, size: Vec2) -> Hsva {
+    let x = ((local_point.x + 0.5 * size.x) / size.x).clamp(0.0, 1.0);
+    let y = ((local_point.y + 0.5 * size.y) / size.y).clamp(0.0, 1.0);
+    let (x, y) = (
+        if x.is_finite() { x } else { 0.0 },
+        if y.is_finite() { y } else { 0.0 },
+    );
+
+    match area {
+        ColorPickerArea::SaturationValue => color.with_saturation(x).with_value(y),
+        ColorPickerArea::Hue => color.with_hue(x * 360.0),
+        ColorPickerArea::Alpha => color.with_alpha(x),
+    }
+}
+
+fn drag_color(
+    commands: &mut Commands,
+    cameras: &Query<(&Camera, &GlobalTransform)>,
+    picker_query: &mut Query<(&mut ColorPicker, Option<&Interaction>)>,
+    part: &ColorPickerPart,
+    camera: Entity,
+    position: Vec2,
+    (transform, computed_node): (&GlobalTransform, &ComputedNode),
+) {
+    let Ok((mut picker, interaction)) = picker_query.get_mut(part.picker) else {
+        return;
+    };
+    if interaction.is_some_and(|interaction| interaction.disabled) {
+        return;
+    }
+
+    let Some(local_point) = pointer_local_point(cameras, camera, position, transform) else {
+        return;
+    };
+
+    let color = color_at(picker.color, part.area, local_point, computed_node.size);
+    change_color(commands, part.picker, &mut picker, color);
+}
+
+fn on_color_area_press(
+    mut event: On<Pointer<Press>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut part_query: Query<(
+        &ColorPickerPart,
+        &mut ColorAreaDrag,
+        &GlobalTransform,
+        &ComputedNode,
+    )>,
+    mut picker_query: Query<(&mut ColorPicker, Option<&Interaction>)>,
+    mut commands: Commands,
+) {
+    let Ok((part, mut drag, transform, computed_node)) = part_query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    drag.camera = Some(event.hit.camera);
+
+    drag_color(
+        &mut commands,
+        &cameras,
+        &mut picker_query,
+        part,
+        event.hit.camera,
+        event.pointer_location.position,
+        (transform, computed_node),
+    );
+}
+
+fn on_color_area_drag(
+    mut event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    part_query: Query<(
+        &ColorPickerPart,
+        &ColorAreaDrag,
+        &GlobalTransform,
+        &ComputedNode,
+    )>,
+    mut picker_query: Query<(&mut ColorPicker, Option<&Interaction>)>,
+    mut commands: Commands,
+) {
+    let Ok((part, drag, transform, computed_node)) = part_query.get(event.entity) else {
+        return;
+    };
+    let Some(camera) = drag.camera else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    drag_color(
+        &mut commands,
+        &cameras,
+        &mut picker_query,
+        part,
+        camera,
+        event.pointer_location.position,
+        (transform, computed_node),
+    );
+}
+
+/// Parses the typed color and stops editing, keeps the color if it's not valid.
+fn commit_hex(
+    commands: &mut Commands,
+    hex_input: &mut ColorHexInput,
+    picker_query: &mut Query<(&mut ColorPicker, Option<&Interaction>)>,
+) {
+    let Some(text) = hex_input.editing.take() else {
+        return;
+    };
+    let Ok((mut picker, _)) = picker_query.get_mut(hex_input.picker) else {
+        return;
+    };
+    let Ok(srgba) = Srgba::hex(text.trim()) else {
+        return;
+    };
+
+    let mut color = Hsva::from(srgba);
+    // Keeps the hue of grays, and the alpha when it's hidden.
+    if color.saturation == 0.0 || color.value == 0.0 {
+        color.hue = picker.color.hue;
+    }
+    if !picker.alpha {
+        color.alpha = picker.color.alpha;
+    }
+
+    change_color(commands, hex_input.picker, &mut picker, color);
+}
+
+fn on_hex_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    mut hex_query: Query<(&mut ColorHexInput, &Text, &Interaction)>,
+    mut picker_query: Query<(&mut ColorPicker, Option<&Interaction>)>,
+    mut commands: Commands,
+) {
+    let Ok((mut hex_input, text, interaction)) = hex_query.get_mut(event.focused_entity) else {
+        return;
+    };
+    if event.input.state != ButtonState::Pressed || interaction.disabled {
+        return;
+    }
+
+    match event.input.key_code {
+        KeyCode::Enter | KeyCode::NumpadEnter => {
+            commit_hex(&mut commands, &mut hex_input, &mut picker_query);
+        }
+        KeyCode::Escape if hex_input.is_editing() => hex_input.editing = None,
+        KeyCode::Backspace => {
+            hex_input
+                .editing
+                .get_or_insert_with(|| text.0.clone())
+                .pop();
+        }
+        _ => {
+            let Some(input) = event
+                .input
+                .text
+                .as_ref()
+                .filter(|input| input.chars().all(|c| c.is_ascii_hexdigit() || c == '#'))
+            else {
+                return;
+            };
+
+            // Typing into a shown color replaces it.
+            hex_input
+                .editing
+                .get_or_insert_with(String::new)
+                .push_str(input);
+        }
+    }
+
+    event.propagate(false);
+}
+
+/// Applies the typed colors of the hex inputs which lost the focus.
+fn hex_blur_system(
+    input_focus: Res<InputFocus>,
+    mut hex_query: Query<(Entity, &mut ColorHexInput)>,
+    mut picker_query: Query<(&mut ColorPicker, Option<&Interaction>)>,
+    mut commands: Commands,
+) {
+    for (entity, mut hex_input) in hex_query.iter_mut() {
+        if hex_input.is_editing() && input_focus.get() != Some(entity) {
+            commit_hex(&mut commands, &mut hex_input, &mut picker_query);
+        }
+    }
+}
+
+/// Shows the colors of the pickers on their parts.
+pub fn color_picker_system(
+    mut picker_query: Query<
+        (
+            &ColorPicker,
+            &ColorPickerParts,
+            Option<&mut AccessibilityNode>,
+        ),
+        Changed<ColorPicker>,
+    >,
+    mut style_query: Query<&mut Style>,
+    mut image_query: Query<&mut ImageNode>,
+    mut hex_query: Query<(&ColorHexInput, &mut Text)>,
+) {
+    for (picker, parts, accessible) in picker_query.iter_mut() {
+        let color = picker.color;
+        let hex = picker.hex();
+
+        if let Some(mut accessible) = accessible {
+            accessible.set_value(hex.as_str());
+        }
+
+        let mut set_style = |entity: Entity, f: &dyn Fn(&mut Style)| {
+            if let Ok(mut style) = style_query.get_mut(entity) {
+                f(&mut style);
+            }
+        };
+
+        let hue = Color::from(Hsva::hsv(color.hue, 1.0, 1.0));
+        set_style(parts.field, &|style| style.background = Some(hue));
+
+        let opaque = Color::from(color.with_alpha(1.0));
+        set_style(parts.preview, &|style| {
+            style.background = Some(Color::from(color));
+        });
+
+        set_style(parts.field_thumb, &|style| {
+            let inner = style.get_mut();
+            inner.inset.left = LengthPercentageAuto::percent(color.saturation);
+            inner.inset.top = LengthPercentageAuto::percent(1.0 - color.value);
+        });
+        set_style(parts.hue_thumb, &|style| {
+            style.get_mut().inset.left = LengthPercentageAuto::percent(color.hue / 360.0);
+        });
+        set_style(parts.alpha_thumb, &|style| {
+            style.get_mut().inset.left = LengthPercentageAuto::percent(color.alpha);
+        });
+        set_style(parts.alpha_bar, &|style| {
+            let display = if picker.alpha {
+                taffy::Display::Flex
+            } else {
+                taffy::Display::None
+            };
+            if style.get_ref().display != display {
+                style.get_mut().display = display;
+            }
+        });
+
+        if let Ok(mut image) = image_query.get_mut(parts.alpha_image) {
+            image.color = opaque;
+        }
+
+        if let Ok((hex_input, mut text)) = hex_query.get_mut(parts.hex)
+            && !hex_input.is_editing()
+            && text.0 != hex
+        {
+            text.0 = hex;
+        }
+    }
+}
+
+/// Shows the typed texts of the hex inputs, and the colors of their pickers when the editing ends,
+/// e.g. when it's cancelled or the typed color is not valid.
+pub fn hex_input_system(
+    picker_query: Query<&ColorPicker>,
+    mut hex_query: Query<(&ColorHexInput, &mut Text), Changed<ColorHexInput>>,
+) {
+    for (hex_input, mut text) in hex_query.iter_mut() {
+        let shown = match &hex_input.editing {
+            Some(editing) => editing.clone(),
+            None => {
+                let Ok(picker) = picker_query.get(hex_input.picker) else {
+                    continue;
+                };
+                picker.hex()
+            }
+        };
+
+        if text.0 != shown {
+            text.0 = shown;
+        }
+    }
+}
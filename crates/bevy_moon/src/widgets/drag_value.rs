@@ -0,0 +1,435 @@
+//! Numeric fields which are scrubbed by dragging.
+
+use accesskit::{Action, ActionData, Role};
+use bevy_a11y::{AccessibilityNode, ActionRequest};
+use bevy_app::{App, Plugin, PostUpdate, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    message::MessageReader,
+    observer::On,
+    query::{Changed, Or},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{
+    ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{FocusedInput, InputFocus, tab_navigation::TabIndex};
+use bevy_picking::{
+    events::{Click, Drag, DragEnd, DragStart, Pointer, Press},
+    pointer::PointerButton,
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_time::Time;
+
+use crate::{UiSystems, elements::text::Text, focus::CaptureArrowKeys, interaction::Interaction};
+
+use super::{
+    ValueChange,
+    slider::{ValueRange, format_value, numeric_node, set_numeric},
+};
+
+/// The distance in logical pixels a drag moves before it starts scrubbing.
+const SCRUB_THRESHOLD: f32 = 2.0;
+
+/// The maximum seconds between the clicks of a double-click.
+const DOUBLE_CLICK_TIME: f32 = 0.5;
+
+/// A numeric field, shows the value as its [`Text`].
+///
+/// Dragging horizontally scrubs the value by `speed` per pixel, relative to the value with a logarithmic scale.
+/// Double-clicking or pressing `Enter` types a new value, committed by `Enter` or losing the focus,
+/// and canceled by `Escape`. While focused, the arrow keys move the value by a step, or by `speed`
+/// without a step. Triggers a [`ValueChange<f32>`] when changed by the user.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(
+    Text,
+    Interaction,
+    TabIndex,
+    CaptureArrowKeys,
+    DragValueState,
+    AccessibilityNode = numeric_node(Role::SpinButton)
+)]
+pub struct DragValue {
+    pub value: f32,
+    pub range: ValueRange,
+    /// The change of the value per dragged pixel.
+    pub speed: f32,
+    /// The decimal places of the shown value.
+    pub precision: usize,
+}
+
+impl Default for DragValue {
+    fn default() -> Self {
+        Self::new(0.0)
+    }
+}
+
+impl DragValue {
+    #[inline]
+    pub const fn new(value: f32) -> Self {
+        Self {
+            value,
+            range: ValueRange::UNBOUNDED,
+            speed: 0.1,
+            precision: 2,
+        }
+    }
+
+    #[inline]
+    pub const fn range(mut self, range: ValueRange) -> Self {
+        self.range = range;
+        self
+    }
+
+    #[inline]
+    pub const fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    #[inline]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the value scrubbed from `start` by a horizontal distance in pixels.
+    pub fn scrub(&self, start: f32, distance: f32) -> f32 {
+        let value = if self.range.is_logarithmic() && start > 0.0 {
+            start * (distance * self.speed).exp()
+        } else {
+            start + distance * self.speed
+        };
+
+        self.range.snap(value)
+    }
+
+    /// Returns the value moved by a number of steps, by `speed` without a step.
+    pub fn step_by(&self, steps: f32) -> f32 {
+        match self.range.step {
+            Some(_) => self.range.step_by(self.value, steps),
+            None => self.scrub(self.value, steps),
+        }
+    }
+
+    /// Returns the shown text of the value.
+    #[inline]
+    pub fn text(&self) -> String {
+        format_value(self.value, self.precision)
+    }
+}
+
+/// The drag and the typed text of a [`DragValue`].
+#[derive(Component, Clone, Debug, Default, PartialEq)]
+pub struct DragValueState {
+    /// The typed text, `None` unless editing.
+    editing: Option<String>,
+    /// The value when the drag started.
+    start: Option<f32>,
+    scrubbing: bool,
+    /// The time of the last click, to detect double-clicks.
+    last_click: Option<f32>,
+}
+
+impl DragValueState {
+    /// Returns `true` while a new value is typed.
+    #[inline]
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+
+    /// Returns `true` while the value is scrubbed.
+    #[inline]
+    pub fn is_scrubbing(&self) -> bool {
+        self.scrubbing
+    }
+
+    /// Returns the typed text while editing.
+    #[inline]
+    pub fn edit_text(&self) -> Option<&str> {
+        self.editing.as_deref()
+    }
+}
+
+/// A plugin that adds [`DragValue`]s.
+pub struct DragValuePlugin;
+
+impl Plugin for DragValuePlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_drag_value_press)
+            .add_observer(on_drag_value_drag_start)
+            .add_observer(on_drag_value_drag)
+            .add_observer(on_drag_value_drag_end)
+            .add_observer(on_drag_value_click)
+            .add_observer(on_drag_value_key)
+            .add_systems(Update, (drag_value_action_system, drag_value_blur_system))
+            .add_systems(PostUpdate, drag_value_system.before(UiSystems::Prepare));
+    }
+}
+
+/// Sets the value of a drag value, triggers a [`ValueChange<f32>`] if it's changed.
+fn change_drag_value(
+    commands: &mut Commands,
+    entity: Entity,
+    drag_value: &mut DragValue,
+    value: f32,
+) {
+    if drag_value.value == value {
+        return;
+    }
+
+    drag_value.value = value;
+
+    commands.trigger(ValueChange { entity, value });
+}
+
+/// Parses the typed text and stops editing, keeps the value if it's not a number.
+fn commit(
+    commands: &mut Commands,
+    entity: Entity,
+    drag_value: &mut DragValue,
+    state: &mut DragValueState,
+) {
+    let Some(text) = state.editing.take() else {
+        return;
+    };
+
+    if let Ok(value) = text.trim().parse::<f32>()
+        && value.is_finite()
+    {
+        let value = drag_value.range.snap(value);
+        change_drag_value(commands, entity, drag_value, value);
+    }
+}
+
+fn on_drag_value_press(event: On<Pointer<Press>>, mut query: Query<&mut DragValueState>) {
+    let Ok(mut state) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button == PointerButton::Primary {
+        state.scrubbing = false;
+    }
+}
+
+fn on_drag_value_drag_start(
+    mut event: On<Pointer<DragStart>>,
+    mut query: Query<(&DragValue, &mut DragValueState, &Interaction)>,
+) {
+    let Ok((drag_value, mut state, interaction)) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary || interaction.disabled || state.is_editing() {
+        return;
+    }
+
+    event.propagate(false);
+
+    state.start = Some(drag_value.value);
+}
+
+fn on_drag_value_drag(
+    mut event: On<Pointer<Drag>>,
+    mut query: Query<(&mut DragValue, &mut DragValueState)>,
+    mut commands: Commands,
+) {
+    let Ok((mut drag_value, mut state)) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    let Some(start) = state.start else {
+        return;
+    };
+
+    event.propagate(false);
+
+    // The screen distance keeps the speed at any zoom.
+    let distance = event.distance.x;
+    if !state.scrubbing && distance.abs() < SCRUB_THRESHOLD {
+        return;
+    }
+    if !state.scrubbing {
+        state.scrubbing = true;
+    }
+
+    let value = drag_value.scrub(start, distance);
+    change_drag_value(&mut commands, event.entity, &mut drag_value, value);
+}
+
+fn on_drag_value_drag_end(event: On<Pointer<DragEnd>>, mut query: Query<&mut DragValueState>) {
+    let Ok(mut state) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    if state.start.is_some() {
+        state.start = None;
+    }
+}
+
+fn on_drag_value_click(
+    mut event: On<Pointer<Click>>,
+    time: Res<Time>,
+    mut input_focus: ResMut<InputFocus>,
+    mut query: Query<(&DragValue, &mut DragValueState, &Interaction)>,
+) {
+    let Ok((drag_value, mut state, interaction)) = query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    if interaction.disabled || state.scrubbing || state.is_editing() {
+        state.last_click = None;
+        return;
+    }
+
+    let now = time.elapsed_secs();
+    if state
+        .last_click
+        .is_some_and(|last_click| now - last_click <= DOUBLE_CLICK_TIME)
+    {
+        state.last_click = None;
+        state.editing = Some(drag_value.text());
+        input_focus.set(event.entity);
+    } else {
+        state.last_click = Some(now);
+    }
+}
+
+fn on_drag_value_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    mut query: Query<(&mut DragValue, &mut DragValueState, &Interaction)>,
+    mut commands: Commands,
+) {
+    let entity = event.focused_entity;
+    let Ok((mut drag_value, mut state, interaction)) = query.get_mut(entity) else {
+        return;
+    };
+    if event.input.state != ButtonState::Pressed || interaction.disabled {
+        return;
+    }
+
+    if let Some(text) = state.editing.as_mut() {
+        match event.input.key_code {
+            KeyCode::Enter | KeyCode::NumpadEnter => {
+                commit(&mut commands, entity, &mut drag_value, &mut state);
+            }
+            KeyCode::Escape => state.editing = None,
+            KeyCode::Backspace => {
+                text.pop();
+            }
+            _ => {
+                match &event.input.text {
+                    Some(input) => text.extend(input.chars().filter(|c| {
+                        c.is_ascii_digit() || matches!(c, '+' | '-' | '.' | 'e' | 'E')
+                    })),
+                    None => return,
+                }
+            }
+        }
+
+        event.propagate(false);
+        return;
+    }
+
+    let value = match event.input.key_code {
+        KeyCode::Enter | KeyCode::NumpadEnter if !event.input.repeat => {
+            event.propagate(false);
+            state.editing = Some(drag_value.text());
+            return;
+        }
+        KeyCode::ArrowRight | KeyCode::ArrowUp => drag_value.step_by(1.0),
+        KeyCode::ArrowLeft | KeyCode::ArrowDown => drag_value.step_by(-1.0),
+        KeyCode::PageUp => drag_value.step_by(10.0),
+        KeyCode::PageDown => drag_value.step_by(-10.0),
+        _ => return,
+    };
+
+    event.propagate(false);
+
+    change_drag_value(&mut commands, entity, &mut drag_value, value);
+}
+
+/// Commits the typed values of the drag values which lost the focus.
+fn drag_value_blur_system(
+    input_focus: Res<InputFocus>,
+    mut query: Query<(Entity, &mut DragValue, &mut DragValueState)>,
+    mut commands: Commands,
+) {
+    for (entity, mut drag_value, mut state) in query.iter_mut() {
+        if state.is_editing() && input_focus.get() != Some(entity) {
+            commit(&mut commands, entity, &mut drag_value, &mut state);
+        }
+    }
+}
+
+/// Changes the drag values by the actions of assistive technologies.
+fn drag_value_action_system(
+    mut requests: MessageReader<ActionRequest>,
+    mut query: Query<(&mut DragValue, &Interaction)>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let Some(entity) = Entity::try_from_bits(request.target.0) else {
+            continue;
+        };
+        let Ok((mut drag_value, interaction)) = query.get_mut(entity) else {
+            continue;
+        };
+        if interaction.disabled {
+            continue;
+        }
+
+        let value = match (request.action, &request.data) {
+            (Action::Increment, _) => drag_value.step_by(1.0),
+            (Action::Decrement, _) => drag_value.step_by(-1.0),
+            (Action::SetValue, Some(ActionData::NumericValue(value))) => {
+                drag_value.range.snap(*value as f32)
+            }
+            _ => continue,
+        };
+
+        change_drag_value(&mut commands, entity, &mut drag_value, value);
+    }
+}
+
+type DragValueTextData = (
+    &'static DragValue,
+    &'static DragValueState,
+    &'static mut Text,
+    Option<&'static mut AccessibilityNode>,
+);
+
+type DragValueTextFilter = Or<(Changed<DragValue>, Changed<DragValueState>)>;
+
+/// Shows the values or the typed texts, and updates the accessibility nodes.
+pub fn drag_value_system(mut query: Query<DragValueTextData, DragValueTextFilter>) {
+    for (drag_value, state, mut text, accessible) in query.iter_mut() {
+        if let Some(mut accessible) = accessible {
+            set_numeric(
+                &mut accessible,
+                drag_value.value,
+                &drag_value.range,
+                drag_value.precision,
+            );
+        }
+
+        let new_text = match state.edit_text() {
+            Some(edit_text) => edit_text.to_owned(),
+            None => drag_value.text(),
+        };
+        if text.0 != new_text {
+            text.0 = new_text;
+        }
+    }
+}
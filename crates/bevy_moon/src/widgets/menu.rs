@@ -0,0 +1,838 @@
+//! Context menus with nested submenus, opened by a secondary click on a node.
+//!
+//! The menus are [`Overlay`]s, they flip to the other side of their anchors at the viewport edges.
+//! The open menu is navigated with the arrow keys, `Enter` selects and `Escape` closes.
+
+use core::fmt;
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::ChildOf,
+    message::MessageReader,
+    observer::On,
+    query::With,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut},
+    world::Ref,
+};
+use bevy_input::{
+    ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{FocusedInput, InputFocus};
+use bevy_math::Vec2;
+use bevy_picking::{
+    Pickable,
+    events::{Click, Pointer},
+    pointer::PointerButton,
+};
+use bevy_platform::sync::Arc;
+use bevy_text::{TextColor, TextFont};
+use bevy_transform::components::GlobalTransform;
+use taffy::{AlignItems, Dimension, FlexDirection, JustifyContent, LengthPercentage};
+
+use crate::{
+    elements::{
+        node::{GlobalZIndex, Node},
+        text::Text,
+    },
+    interaction::{Disabled, Interaction},
+    overlay::{Overlay, OverlayAlign, OverlayPlacement},
+    style::{BorderColor, Corners, Style},
+};
+
+use super::PopupPresses;
+
+/// Opens a menu with a secondary click on the node.
+#[derive(Component, Clone, Debug, Default)]
+#[require(Node)]
+pub struct ContextMenu {
+    pub entries: Vec<MenuEntry>,
+}
+
+impl ContextMenu {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            entries: Vec::new(),
+        }
+    }
+
+    #[inline]
+    pub fn item(mut self, item: MenuItem) -> Self {
+        self.entries.push(MenuEntry::Item(item));
+        self
+    }
+
+    #[inline]
+    pub fn separator(mut self) -> Self {
+        self.entries.push(MenuEntry::Separator);
+        self
+    }
+
+    #[inline]
+    pub fn submenu(mut self, submenu: Submenu) -> Self {
+        self.entries.push(MenuEntry::Submenu(submenu));
+        self
+    }
+}
+
+#[derive(Clone, Debug)]
+pub enum MenuEntry {
+    Item(MenuItem),
+    Separator,
+    Submenu(Submenu),
+}
+
+impl MenuEntry {
+    /// Returns `true` if the entry can be highlighted and selected.
+    #[inline]
+    pub fn is_enabled(&self) -> bool {
+        match self {
+            Self::Item(item) => !item.disabled,
+            Self::Separator => false,
+            Self::Submenu(submenu) => !submenu.disabled,
+        }
+    }
+}
+
+/// Triggers the [`MenuSelect`] of an item on the node.
+type SelectAction = Arc<dyn Fn(&mut Commands, Entity) + Send + Sync>;
+
+/// An item of a menu, triggers a [`MenuSelect`] with its action when selected.
+#[derive(Clone)]
+pub struct MenuItem {
+    pub label: String,
+    /// The keyboard shortcut displayed next to the label.
+    pub shortcut: Option<String>,
+    pub disabled: bool,
+    select: SelectAction,
+}
+
+impl MenuItem {
+    #[inline]
+    pub fn new<A>(label: impl Into<String>, action: A) -> Self
+    where
+        A: Clone + Send + Sync + 'static,
+    {
+        Self {
+            label: label.into(),
+            shortcut: None,
+            disabled: false,
+            select: Arc::new(move |commands, entity| {
+                commands.trigger(MenuSelect {
+                    entity,
+                    action: action.clone(),
+                });
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn shortcut(mut self, shortcut: impl Into<String>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    #[inline]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+impl fmt::Debug for MenuItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MenuItem")
+            .field("label", &self.label)
+            .field("shortcut", &self.shortcut)
+            .field("disabled", &self.disabled)
+            .finish_non_exhaustive()
+    }
+}
+
+/// An entry which opens a nested menu.
+#[derive(Clone, Debug)]
+pub struct Submenu {
+    pub label: String,
+    pub disabled: bool,
+    pub entries: Vec<MenuEntry>,
+}
+
+impl Submenu {
+    #[inline]
+    pub fn new(label: impl Into<String>, menu: ContextMenu) -> Self {
+        Self {
+            label: label.into(),
+            disabled: false,
+            entries: menu.entries,
+        }
+    }
+
+    #[inline]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// Triggered on the node of the [`ContextMenu`] when an item is selected, with the action of the item.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct MenuSelect<A: Clone + Send + Sync + 'static> {
+    pub entity: Entity,
+    pub action: A,
+}
+
+/// The look of the menus.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct MenuStyle {
+    pub background: Color,
+    pub border_color: Color,
+    pub highlight: Color,
+    pub separator_color: Color,
+    pub text_color: Color,
+    pub shortcut_color: Color,
+    pub disabled_color: Color,
+    pub font_size: f32,
+    pub padding: f32,
+    /// The horizontal and vertical padding of the items.
+    pub item_padding: Vec2,
+    pub corner_radius: f32,
+    pub min_width: f32,
+}
+
+impl Default for MenuStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::srgb(0.15, 0.15, 0.17),
+            border_color: Color::srgb(0.28, 0.28, 0.31),
+            highlight: Color::srgb(0.24, 0.42, 0.8),
+            separator_color: Color::srgb(0.28, 0.28, 0.31),
+            text_color: Color::srgb(0.92, 0.92, 0.92),
+            shortcut_color: Color::srgb(0.6, 0.6, 0.62),
+            disabled_color: Color::srgb(0.45, 0.45, 0.47),
+            font_size: 13.0,
+            padding: 4.0,
+            item_padding: Vec2::new(10.0, 4.0),
+            corner_radius: 4.0,
+            min_width: 160.0,
+        }
+    }
+}
+
+/// A popup of the open menu, the root menu has a depth of `0`.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MenuPopup {
+    /// The node of the [`ContextMenu`].
+    pub source: Entity,
+    pub depth: usize,
+}
+
+/// An entry of a [`MenuPopup`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct MenuEntryNode {
+    pub depth: usize,
+    pub index: usize,
+}
+
+/// The open menu and its submenus.
+#[derive(Resource, Debug, Default)]
+pub struct ActiveMenu {
+    source: Option<Entity>,
+    /// The node placed at the pointer, the root menu is placed next to it.
+    anchor: Option<Entity>,
+    levels: Vec<MenuLevel>,
+    /// The focus before the menu was opened, restored when it closes.
+    previous_focus: Option<Entity>,
+}
+
+#[derive(Debug)]
+struct MenuLevel {
+    popup: Entity,
+    entries: Vec<MenuEntry>,
+    nodes: Vec<Entity>,
+    highlighted: Option<usize>,
+    /// The index of the submenu entry in the parent level.
+    opener: Option<usize>,
+}
+
+impl ActiveMenu {
+    /// Returns the node whose menu is open.
+    #[inline]
+    pub fn source(&self) -> Option<Entity> {
+        self.source
+    }
+
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.source.is_some()
+    }
+
+    fn open(
+        &mut self,
+        commands: &mut Commands,
+        style: &MenuStyle,
+        input_focus: &mut InputFocus,
+        source: Entity,
+        anchor: Entity,
+        entries: Vec<MenuEntry>,
+    ) {
+        self.close(commands, input_focus);
+
+        let level = spawn_level(commands, style, source, anchor, 0, None, entries);
+
+        self.previous_focus = input_focus.get();
+        input_focus.set(level.popup);
+
+        self.source = Some(source);
+        self.anchor = Some(anchor);
+        self.levels.push(level);
+    }
+
+    /// Opens the submenu of the entry, and focuses it if `focus` is set.
+    fn open_submenu(
+        &mut self,
+        commands: &mut Commands,
+        style: &MenuStyle,
+        input_focus: &mut InputFocus,
+        depth: usize,
+        index: usize,
+        focus: bool,
+    ) {
+        let Some(source) = self.source else {
+            return;
+        };
+        let Some(parent) = self.levels.get(depth) else {
+            return;
+        };
+        let Some(MenuEntry::Submenu(submenu)) = parent.entries.get(index) else {
+            return;
+        };
+        if submenu.disabled {
+            return;
+        }
+
+        let is_open = self
+            .levels
+            .get(depth + 1)
+            .is_some_and(|level| level.opener == Some(index));
+
+        if !is_open {
+            let anchor = parent.nodes[index];
+            let level = spawn_level(
+                commands,
+                style,
+                source,
+                anchor,
+                depth + 1,
+                Some(index),
+                submenu.entries.clone(),
+            );
+
+            self.close_from(commands, depth + 1);
+            self.levels.push(level);
+        }
+
+        if focus {
+            let level = &mut self.levels[depth + 1];
+            if level.highlighted.is_none() {
+                level.highlighted = level.entries.iter().position(MenuEntry::is_enabled);
+            }
+            input_focus.set(level.popup);
+        }
+    }
+
+    /// Closes the levels from the depth.
+    fn close_from(&mut self, commands: &mut Commands, depth: usize) {
+        if depth >= self.levels.len() {
+            return;
+        }
+
+        for level in self.levels.drain(depth..) {
+            commands.entity(level.popup).try_despawn();
+        }
+    }
+
+    fn close(&mut self, commands: &mut Commands, input_focus: &mut InputFocus) {
+        let focused = input_focus
+            .get()
+            .is_some_and(|focus| self.levels.iter().any(|level| level.popup == focus));
+
+        self.close_from(commands, 0);
+
+        if let Some(anchor) = self.anchor.take() {
+            commands.entity(anchor).try_despawn();
+        }
+
+        if focused {
+            match self.previous_focus.take() {
+                Some(previous) => input_focus.set(previous),
+                None => input_focus.clear(),
+            }
+        }
+
+        self.source = None;
+        self.previous_focus = None;
+    }
+
+    /// Selects an item, or opens a submenu.
+    fn activate(
+        &mut self,
+        commands: &mut Commands,
+        style: &MenuStyle,
+        input_focus: &mut InputFocus,
+        depth: usize,
+        index: usize,
+    ) {
+        let Some(source) = self.source else {
+            return;
+        };
+        let Some(entry) = self
+            .levels
+            .get(depth)
+            .and_then(|level| level.entries.get(index))
+        else {
+            return;
+        };
+
+        match entry {
+            MenuEntry::Item(item) if !item.disabled => {
+                let select = item.select.clone();
+                self.close(commands, input_focus);
+                select(commands, source);
+            }
+            MenuEntry::Submenu(_) => {
+                self.open_submenu(commands, style, input_focus, depth, index, true);
+            }
+            _ => {}
+        }
+    }
+
+    /// Moves the highlight to the next enabled entry in the direction, wrapping around.
+    fn move_highlight(&mut self, depth: usize, forward: bool) {
+        let Some(level) = self.levels.get_mut(depth) else {
+            return;
+        };
+
+        let len = level.entries.len();
+        if len == 0 {
+            return;
+        }
+
+        let mut index = match (level.highlighted, forward) {
+            (Some(index), _) => index,
+            (None, true) => len - 1,
+            (None, false) => 0,
+        };
+
+        for _ in 0..len {
+            index = if forward {
+                (index + 1) % len
+            } else {
+                (index + len - 1) % len
+            };
+
+            if level.entries[index].is_enabled() {
+                level.highlighted = Some(index);
+                return;
+            }
+        }
+    }
+}
+
+/// A plugin that opens [`ContextMenu`]s.
+pub struct ContextMenuPlugin;
+
+impl Plugin for ContextMenuPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuStyle>()
+            .init_resource::<ActiveMenu>()
+            .add_systems(
+                Update,
+                (
+                    context_menu_system,
+                    menu_hover_system,
+                    menu_click_system,
+                    menu_highlight_system,
+                )
+                    .chain(),
+            )
+            .add_observer(on_menu_key);
+    }
+}
+
+/// Opens the menus with a secondary press, closes them with a press outside.
+pub fn context_menu_system(
+    style: Res<MenuStyle>,
+    mut presses: PopupPresses<MenuPopup>,
+    menu_query: Query<(&ContextMenu, &GlobalTransform, Option<&Interaction>)>,
+    parent_query: Query<&ChildOf>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveMenu>,
+    mut commands: Commands,
+) {
+    let mut pressed = false;
+    let mut pressed_inside = false;
+    let mut opened = None;
+
+    for (press, inside) in presses.read() {
+        pressed = true;
+
+        if inside {
+            pressed_inside = true;
+            continue;
+        }
+
+        if press.button != PointerButton::Secondary {
+            continue;
+        }
+        let Some(position) = press.hit.position else {
+            continue;
+        };
+
+        // The nearest node with a menu.
+        let Some((source, (menu, transform, _))) = core::iter::once(press.entity)
+            .chain(parent_query.iter_ancestors(press.entity))
+            .find_map(|entity| Some((entity, menu_query.get(entity).ok()?)))
+            .filter(|(_, (.., interaction))| !interaction.is_some_and(|i| i.disabled))
+        else {
+            continue;
+        };
+
+        let mut transform = transform.compute_transform();
+        transform.translation = position;
+        opened = Some((source, menu.entries.clone(), transform));
+    }
+
+    let mouse_pressed = presses.mouse_just_pressed();
+    let source_removed = active
+        .source
+        .is_some_and(|source| !menu_query.contains(source));
+
+    if active.is_open() && (source_removed || (!pressed_inside && (pressed || mouse_pressed))) {
+        active.close(&mut commands, &mut input_focus);
+    }
+
+    let Some((source, entries, transform)) = opened else {
+        return;
+    };
+
+    let anchor = commands
+        .spawn((
+            Node,
+            Pickable::IGNORE,
+            transform,
+            Style::from(taffy::Style {
+                size: taffy::Size {
+                    width: Dimension::length(1.0),
+                    height: Dimension::length(1.0),
+                },
+                ..Default::default()
+            }),
+        ))
+        .id();
+
+    active.open(
+        &mut commands,
+        &style,
+        &mut input_focus,
+        source,
+        anchor,
+        entries,
+    );
+}
+
+/// Highlights the hovered entries, and opens their submenus.
+pub fn menu_hover_system(
+    style: Res<MenuStyle>,
+    entry_query: Query<(Entity, &MenuEntryNode, Ref<Interaction>)>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveMenu>,
+    mut commands: Commands,
+) {
+    for (entity, entry, interaction) in entry_query.iter() {
+        if !interaction.is_changed() || !interaction.hovered || interaction.disabled {
+            continue;
+        }
+
+        let Some(level) = active.levels.get_mut(entry.depth) else {
+            continue;
+        };
+        if level.nodes.get(entry.index) != Some(&entity) {
+            continue;
+        }
+
+        level.highlighted = Some(entry.index);
+
+        if matches!(level.entries[entry.index], MenuEntry::Submenu(_)) {
+            active.open_submenu(
+                &mut commands,
+                &style,
+                &mut input_focus,
+                entry.depth,
+                entry.index,
+                false,
+            );
+        } else {
+            active.close_from(&mut commands, entry.depth + 1);
+        }
+    }
+}
+
+/// Selects the clicked entries.
+pub fn menu_click_system(
+    style: Res<MenuStyle>,
+    mut click_reader: MessageReader<Pointer<Click>>,
+    entry_query: Query<&MenuEntryNode>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveMenu>,
+    mut commands: Commands,
+) {
+    for click in click_reader.read() {
+        if click.button != PointerButton::Primary {
+            continue;
+        }
+        let Ok(entry) = entry_query.get(click.entity) else {
+            continue;
+        };
+        if active
+            .levels
+            .get(entry.depth)
+            .and_then(|level| level.nodes.get(entry.index))
+            != Some(&click.entity)
+        {
+            continue;
+        }
+
+        active.activate(
+            &mut commands,
+            &style,
+            &mut input_focus,
+            entry.depth,
+            entry.index,
+        );
+    }
+}
+
+/// Paints the highlighted entries, and the entries whose submenus are open.
+pub fn menu_highlight_system(
+    menu_style: Res<MenuStyle>,
+    active: Res<ActiveMenu>,
+    mut style_query: Query<&mut Style, With<MenuEntryNode>>,
+) {
+    for (depth, level) in active.levels.iter().enumerate() {
+        let opener = active.levels.get(depth + 1).and_then(|next| next.opener);
+
+        for (index, &node) in level.nodes.iter().enumerate() {
+            let Ok(mut style) = style_query.get_mut(node) else {
+                continue;
+            };
+
+            let highlighted = level.highlighted == Some(index) || opener == Some(index);
+            let background = highlighted.then_some(menu_style.highlight);
+            if style.background != background {
+                style.background = background;
+            }
+        }
+    }
+}
+
+fn on_menu_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    style: Res<MenuStyle>,
+    popup_query: Query<&MenuPopup>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveMenu>,
+    mut commands: Commands,
+) {
+    let Ok(popup) = popup_query.get(event.focused_entity) else {
+        return;
+    };
+    if event.input.state != ButtonState::Pressed {
+        return;
+    }
+
+    let depth = popup.depth;
+    let highlighted = active.levels.get(depth).and_then(|level| level.highlighted);
+
+    match event.input.key_code {
+        KeyCode::ArrowDown => active.move_highlight(depth, true),
+        KeyCode::ArrowUp => active.move_highlight(depth, false),
+        KeyCode::ArrowRight => {
+            if let Some(index) = highlighted {
+                active.open_submenu(&mut commands, &style, &mut input_focus, depth, index, true);
+            }
+        }
+        KeyCode::ArrowLeft | KeyCode::Escape if depth > 0 => {
+            active.close_from(&mut commands, depth);
+            input_focus.set(active.levels[depth - 1].popup);
+        }
+        KeyCode::Escape => active.close(&mut commands, &mut input_focus),
+        KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space => {
+            if let Some(index) = highlighted {
+                active.activate(&mut commands, &style, &mut input_focus, depth, index);
+            }
+        }
+        _ => return,
+    }
+
+    event.propagate(false);
+}
+
+fn spawn_level(
+    commands: &mut Commands,
+    style: &MenuStyle,
+    source: Entity,
+    anchor: Entity,
+    depth: usize,
+    opener: Option<usize>,
+    entries: Vec<MenuEntry>,
+) -> MenuLevel {
+    let popup = commands
+        .spawn((
+            Overlay::new(anchor)
+                .placement(OverlayPlacement::Right)
+                .align(OverlayAlign::Start),
+            // The submenus are stacked above their parents.
+            GlobalZIndex(depth as i32),
+            MenuPopup { source, depth },
+            AccessibilityNode::from(accesskit::Node::new(Role::Menu)),
+            Style::from(taffy::Style {
+                flex_direction: FlexDirection::Column,
+                min_size: taffy::Size {
+                    width: Dimension::length(style.min_width),
+                    height: Dimension::auto(),
+                },
+                padding: taffy::Rect::length(style.padding),
+                border: taffy::Rect::length(1.0),
+                ..Default::default()
+            })
+            .background(style.background)
+            .border_color(BorderColor::all(style.border_color))
+            .corner_radii(Corners::all(style.corner_radius)),
+        ))
+        .id();
+
+    let nodes = entries
+        .iter()
+        .enumerate()
+        .map(|(index, entry)| spawn_entry(commands, style, popup, depth, index, entry))
+        .collect();
+
+    MenuLevel {
+        popup,
+        entries,
+        nodes,
+        highlighted: None,
+        opener,
+    }
+}
+
+fn spawn_entry(
+    commands: &mut Commands,
+    style: &MenuStyle,
+    popup: Entity,
+    depth: usize,
+    index: usize,
+    entry: &MenuEntry,
+) -> Entity {
+    let (label, trailing, disabled) = match entry {
+        MenuEntry::Item(item) => (&item.label, item.shortcut.clone(), item.disabled),
+        MenuEntry::Submenu(submenu) => (&submenu.label, Some("›".into()), submenu.disabled),
+        MenuEntry::Separator => {
+            return commands
+                .spawn((
+                    Node,
+                    ChildOf(popup),
+                    MenuEntryNode { depth, index },
+                    Pickable::IGNORE,
+                    Style::from(taffy::Style {
+                        size: taffy::Size {
+                            width: Dimension::auto(),
+                            height: Dimension::length(1.0),
+                        },
+                        margin: taffy::Rect {
+                            left: LengthPercentage::length(0.0).into(),
+                            right: LengthPercentage::length(0.0).into(),
+                            top: LengthPercentage::length(style.padding).into(),
+                            bottom: LengthPercentage::length(style.padding).into(),
+                        },
+                        ..Default::default()
+                    })
+                    .background(style.separator_color),
+                ))
+                .id();
+        }
+    };
+
+    let mut accessible = accesskit::Node::new(Role::MenuItem);
+    accessible.set_label(label.as_str());
+
+    let text_color = if disabled {
+        style.disabled_color
+    } else {
+        style.text_color
+    };
+    let trailing_color = if disabled {
+        style.disabled_color
+    } else {
+        style.shortcut_color
+    };
+
+    let mut entity = commands.spawn((
+        Node,
+        ChildOf(popup),
+        MenuEntryNode { depth, index },
+        Interaction::default(),
+        AccessibilityNode::from(accessible),
+        Style::from(taffy::Style {
+            flex_direction: FlexDirection::Row,
+            justify_content: Some(JustifyContent::SpaceBetween),
+            align_items: Some(AlignItems::Center),
+            gap: taffy::Size {
+                width: LengthPercentage::length(24.0),
+                height: LengthPercentage::length(0.0),
+            },
+            padding: taffy::Rect {
+                left: LengthPercentage::length(style.item_padding.x),
+                right: LengthPercentage::length(style.item_padding.x),
+                top: LengthPercentage::length(style.item_padding.y),
+                bottom: LengthPercentage::length(style.item_padding.y),
+            },
+            ..Default::default()
+        })
+        .corner_radii(Corners::all(style.corner_radius)),
+    ));
+
+    if disabled {
+        entity.insert(Disabled);
+    }
+
+    entity.with_children(|parent| {
+        parent.spawn((
+            Text::new(label.as_str()),
+            TextFont::from_font_size(style.font_size),
+            TextColor(text_color),
+            Pickable::IGNORE,
+        ));
+
+        if let Some(trailing) = trailing {
+            parent.spawn((
+                Text::new(trailing),
+                TextFont::from_font_size(style.font_size),
+                TextColor(trailing_color),
+                Pickable::IGNORE,
+            ));
+        }
+    });
+
+    entity.id()
+}
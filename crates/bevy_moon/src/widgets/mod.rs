@@ -0,0 +1,251 @@
+//! Standard widgets built from the moon elements.
+//!
+//! The widgets are plain nodes with a few required components, styled with [`Style`](crate::style::Style)
+//! and [`StyleStates`](crate::states::StyleStates) like any other node.
+
+pub mod button;
+pub mod checkbox;
+pub mod color_picker;
+pub mod drag_value;
+pub mod menu;
+pub mod modal;
+pub mod radio;
+pub mod select;
+pub mod slider;
+pub mod splitter;
+pub mod table;
+pub mod tabs;
+pub mod toast;
+pub mod tree;
+
+use accesskit::{Action, Role};
+use bevy_a11y::{AccessibilityNode, ActionRequest};
+use bevy_app::{App, Plugin, Update};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::ChildOf,
+    message::MessageReader,
+    observer::On,
+    query::With,
+    reflect::ReflectComponent,
+    system::{Commands, Query, Res, SystemParam},
+};
+use bevy_input::{
+    ButtonInput, ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+    mouse::MouseButton,
+};
+use bevy_input_focus::{FocusedInput, tab_navigation::TabIndex};
+use bevy_picking::{
+    events::{Click, Pointer, Press},
+    pointer::PointerButton,
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+
+use crate::{
+    elements::node::Node,
+    interaction::{Checked, Interaction},
+};
+
+use checkbox::CheckboxPlugin;
+use color_picker::ColorPickerPlugin;
+use drag_value::DragValuePlugin;
+use menu::ContextMenuPlugin;
+use modal::ModalPlugin;
+use radio::RadioPlugin;
+use select::SelectPlugin;
+use slider::SliderPlugin;
+use splitter::SplitterPlugin;
+use table::TablePlugin;
+use tabs::TabsPlugin;
+use toast::ToastPlugin;
+use tree::TreePlugin;
+
+/// Makes a node activatable by a primary click, or by `Enter` and `Space` while it's focused.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Node, Interaction, TabIndex)]
+pub struct Activatable;
+
+/// Triggered on an [`Activatable`] node when it's activated, unless it's disabled.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct Activate {
+    pub entity: Entity,
+}
+
+/// Triggered on a widget when its value is changed by the user.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct ValueChange<T: Clone + Send + Sync + 'static> {
+    pub entity: Entity,
+    pub value: T,
+}
+
+/// A plugin that adds all the widgets.
+pub struct WidgetsPlugin;
+
+impl Plugin for WidgetsPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_activate_click)
+            .add_observer(on_activate_key)
+            .add_systems(Update, activate_action_system)
+            .add_plugins((
+                CheckboxPlugin,
+                RadioPlugin,
+                SliderPlugin,
+                DragValuePlugin,
+                ColorPickerPlugin,
+                SelectPlugin,
+                ContextMenuPlugin,
+                TabsPlugin,
+                SplitterPlugin,
+                TreePlugin,
+                TablePlugin,
+                ModalPlugin,
+                ToastPlugin,
+            ));
+    }
+}
+
+fn on_activate_click(
+    mut event: On<Pointer<Click>>,
+    query: Query<&Interaction, With<Activatable>>,
+    mut commands: Commands,
+) {
+    let Ok(interaction) = query.get(event.entity) else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    if !interaction.disabled {
+        commands.trigger(Activate {
+            entity: event.entity,
+        });
+    }
+}
+
+fn on_activate_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    query: Query<&Interaction, With<Activatable>>,
+    mut commands: Commands,
+) {
+    let Ok(interaction) = query.get(event.focused_entity) else {
+        return;
+    };
+    let input = &event.input;
+    if input.state != ButtonState::Pressed
+        || input.repeat
+        || !matches!(
+            input.key_code,
+            KeyCode::Enter | KeyCode::NumpadEnter | KeyCode::Space
+        )
+    {
+        return;
+    }
+
+    event.propagate(false);
+
+    if !interaction.disabled {
+        commands.trigger(Activate {
+            entity: event.focused_entity,
+        });
+    }
+}
+
+/// Activates the nodes clicked by an assistive technology.
+fn activate_action_system(
+    mut requests: MessageReader<ActionRequest>,
+    query: Query<&Interaction, With<Activatable>>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        if request.action != Action::Click {
+            continue;
+        }
+
+        let Some(entity) = Entity::try_from_bits(request.target.0) else {
+            continue;
+        };
+
+        if query
+            .get(entity)
+            .is_ok_and(|interaction| !interaction.disabled)
+        {
+            commands.trigger(Activate { entity });
+        }
+    }
+}
+
+/// The pointer presses inside or outside of the popups with the `P` marker.
+#[derive(SystemParam)]
+pub struct PopupPresses<'w, 's, P: Component> {
+    mouse: Option<Res<'w, ButtonInput<MouseButton>>>,
+    press_reader: MessageReader<'w, 's, Pointer<Press>>,
+    popup_query: Query<'w, 's, (), With<P>>,
+    parent_query: Query<'w, 's, &'static ChildOf>,
+}
+
+impl<P: Component> PopupPresses<'_, '_, P> {
+    /// Reads the presses, with whether they are inside a popup.
+    pub fn read(&mut self) -> impl Iterator<Item = (&Pointer<Press>, bool)> {
+        let Self {
+            press_reader,
+            popup_query,
+            parent_query,
+            ..
+        } = self;
+
+        press_reader.read().map(|press| {
+            let inside = core::iter::once(press.entity)
+                .chain(parent_query.iter_ancestors(press.entity))
+                .any(|entity| popup_query.contains(entity));
+            (press, inside)
+        })
+    }
+
+    /// Skips the presses.
+    pub fn clear(&mut self) {
+        self.press_reader.clear();
+    }
+
+    /// Returns `true` if a mouse button has been pressed, even outside of the nodes.
+    pub fn mouse_just_pressed(&self) -> bool {
+        self.mouse
+            .as_ref()
+            .is_some_and(|mouse| mouse.get_just_pressed().next().is_some())
+    }
+}
+
+/// Makes an accessibility node of the role which can be clicked.
+fn accessible_node(role: Role) -> AccessibilityNode {
+    let mut node = accesskit::Node::new(role);
+    node.add_action(Action::Click);
+    AccessibilityNode::from(node)
+}
+
+/// Marks the node as [`Checked`], and updates its accessibility node.
+fn set_checked(
+    commands: &mut Commands,
+    entity: Entity,
+    checked: bool,
+    accessible: Option<&mut AccessibilityNode>,
+) {
+    if checked {
+        commands.entity(entity).try_insert(Checked);
+    } else {
+        commands.entity(entity).try_remove::<Checked>();
+    }
+
+    if let Some(accessible) = accessible {
+        accessible.set_toggled(if checked {
+            accesskit::Toggled::True
+        } else {
+            accesskit::Toggled::False
+        });
+    }
+}
@@ -0,0 +1,259 @@
+//! Modal dialogs.
+//!
+//! A [`Modal`] is a [`ScreenSpace`] root which covers the viewport of its camera with a backdrop,
+//! and centers its children on it, the dialog. It stays in place while the camera pans and zooms.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    lifecycle::{Add, Remove},
+    observer::On,
+    query::With,
+    resource::Resource,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{
+    ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{
+    FocusedInput, InputFocus,
+    tab_navigation::{TabGroup, TabIndex},
+};
+use bevy_picking::{
+    events::{Click, Pointer},
+    pointer::PointerButton,
+};
+use taffy::{AlignItems, Dimension, JustifyContent};
+
+use crate::{
+    computed::ComputedNode,
+    elements::node::{Node, TopLayer},
+    screen_space::{ScreenAnchor, ScreenSpace},
+    stack::UiStackMap,
+    style::Style,
+};
+
+/// A dialog which dims the rest of the UI of its camera, blocks the picking behind it
+/// and traps the focus inside it.
+///
+/// Spawn it as a root node with the dialog as its children, its camera is chosen by its
+/// [`ScreenSpace`]. The focus moves into the modal when it's opened, and back to the previous node
+/// when it's removed. Pressing `Escape` or clicking the backdrop triggers a [`ModalDismiss`],
+/// and despawns the modal if it's `dismissable`.
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(
+    Node,
+    TopLayer,
+    ScreenSpace = ScreenSpace::new(ScreenAnchor::Center),
+    TabGroup = TabGroup::modal(),
+    AccessibilityNode = modal_accessible_node()
+)]
+pub struct Modal {
+    pub dismissable: bool,
+    /// The focus before the modal is opened.
+    previous_focus: Option<Entity>,
+}
+
+impl Default for Modal {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Modal {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            dismissable: true,
+            previous_focus: None,
+        }
+    }
+
+    #[inline]
+    pub const fn dismissable(mut self, dismissable: bool) -> Self {
+        self.dismissable = dismissable;
+        self
+    }
+}
+
+fn modal_accessible_node() -> AccessibilityNode {
+    let mut node = accesskit::Node::new(Role::Dialog);
+    node.set_modal();
+    AccessibilityNode::from(node)
+}
+
+/// Triggered on a [`Modal`] when `Escape` is pressed inside it or its backdrop is clicked.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct ModalDismiss {
+    pub entity: Entity,
+}
+
+/// The look of the modals.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ModalStyle {
+    pub backdrop: Color,
+}
+
+impl Default for ModalStyle {
+    fn default() -> Self {
+        Self {
+            backdrop: Color::srgba(0.0, 0.0, 0.0, 0.5),
+        }
+    }
+}
+
+/// A plugin that adds [`Modal`]s.
+pub struct ModalPlugin;
+
+impl Plugin for ModalPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ModalStyle>()
+            .add_observer(on_add_modal)
+            .add_observer(on_remove_modal)
+            .add_observer(on_modal_click)
+            .add_observer(on_modal_key)
+            .add_systems(Update, modal_focus_system);
+    }
+}
+
+fn on_add_modal(
+    event: On<Add, Modal>,
+    modal_style: Res<ModalStyle>,
+    input_focus: Res<InputFocus>,
+    mut modal_query: Query<(&mut Modal, &mut Style)>,
+) {
+    let Ok((mut modal, mut style)) = modal_query.get_mut(event.entity) else {
+        return;
+    };
+
+    modal.previous_focus = input_focus.get();
+
+    let inner = style.get_mut();
+    inner.size = taffy::Size {
+        width: Dimension::percent(1.0),
+        height: Dimension::percent(1.0),
+    };
+    inner.justify_content = Some(JustifyContent::Center);
+    inner.align_items = Some(AlignItems::Center);
+    style.background = Some(modal_style.backdrop);
+}
+
+/// Restores the focus of a removed modal, unless it has moved out of the modal.
+fn on_remove_modal(
+    event: On<Remove, Modal>,
+    modal_query: Query<&Modal>,
+    parent_query: Query<&ChildOf>,
+    mut input_focus: ResMut<InputFocus>,
+) {
+    let Ok(modal) = modal_query.get(event.entity) else {
+        return;
+    };
+
+    let inside = input_focus.get().is_none_or(|focused| {
+        focused == event.entity
+            || parent_query
+                .iter_ancestors(focused)
+                .any(|ancestor| ancestor == event.entity)
+    });
+    if !inside {
+        return;
+    }
+
+    match modal.previous_focus {
+        Some(previous_focus) => input_focus.set(previous_focus),
+        None => input_focus.clear(),
+    }
+}
+
+fn dismiss(commands: &mut Commands, entity: Entity, modal: &Modal) {
+    commands.trigger(ModalDismiss { entity });
+
+    if modal.dismissable {
+        commands.entity(entity).try_despawn();
+    }
+}
+
+/// Dismisses the modal with a click on the backdrop, outside of the dialog.
+fn on_modal_click(
+    mut event: On<Pointer<Click>>,
+    modal_query: Query<&Modal>,
+    mut commands: Commands,
+) {
+    let Ok(modal) = modal_query.get(event.entity) else {
+        return;
+    };
+
+    event.propagate(false);
+
+    if event.button != PointerButton::Primary || event.original_event_target() != event.entity {
+        return;
+    }
+
+    dismiss(&mut commands, event.entity, modal);
+}
+
+fn on_modal_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    modal_query: Query<&Modal>,
+    mut commands: Commands,
+) {
+    let Ok(modal) = modal_query.get(event.focused_entity) else {
+        return;
+    };
+
+    // The keys never leave a modal.
+    event.propagate(false);
+
+    if event.input.state != ButtonState::Pressed || event.input.key_code != KeyCode::Escape {
+        return;
+    }
+
+    dismiss(&mut commands, event.focused_entity, modal);
+}
+
+/// Moves the focus into the topmost modal, on its first focusable node or on the modal itself.
+pub fn modal_focus_system(
+    ui_stack_map: Res<UiStackMap>,
+    modal_query: Query<(Entity, &ComputedNode), With<Modal>>,
+    parent_query: Query<&ChildOf>,
+    children_query: Query<&Children>,
+    tab_index_query: Query<&TabIndex>,
+    mut input_focus: ResMut<InputFocus>,
+) {
+    // The stack indices are only ordered in the stack of a camera, the stacks of the cameras
+    // with higher orders are above.
+    let Some(modal) = modal_query
+        .iter()
+        .filter_map(|(entity, computed_node)| {
+            let camera_entity = ui_stack_map.find_camera(entity)?;
+            let order = ui_stack_map.get(&camera_entity)?.order;
+            Some((entity, (order, computed_node.stack_index)))
+        })
+        .max_by_key(|(_, key)| *key)
+        .map(|(entity, _)| entity)
+    else {
+        return;
+    };
+
+    if input_focus.get().is_some_and(|focused| {
+        focused == modal
+            || parent_query
+                .iter_ancestors(focused)
+                .any(|ancestor| ancestor == modal)
+    }) {
+        return;
+    }
+
+    let focusable = children_query
+        .iter_descendants_depth_first(modal)
+        .find(|&entity| tab_index_query.get(entity).is_ok_and(|index| index.0 >= 0));
+
+    input_focus.set(focusable.unwrap_or(modal));
+}
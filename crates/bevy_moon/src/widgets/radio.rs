@@ -0,0 +1,114 @@
+//! Radio buttons and their groups.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    observer::On,
+    query::{Changed, With},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+
+use crate::elements::node::Node;
+
+use super::{Activatable, Activate, ValueChange, accessible_node, set_checked};
+
+/// Groups the [`RadioButton`]s of its descendants, only one of them is checked.
+///
+/// Triggers a [`ValueChange<Entity>`] with the checked radio button.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(
+    Node,
+    AccessibilityNode = AccessibilityNode::from(accesskit::Node::new(Role::RadioGroup))
+)]
+pub struct RadioGroup;
+
+/// A radio button, checked when activated and unchecks the others of its [`RadioGroup`].
+///
+/// Triggers a [`ValueChange<bool>`] when it becomes checked.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Activatable, AccessibilityNode = accessible_node(Role::RadioButton))]
+pub struct RadioButton {
+    pub checked: bool,
+}
+
+impl RadioButton {
+    #[inline]
+    pub const fn new(checked: bool) -> Self {
+        Self { checked }
+    }
+}
+
+/// A plugin that adds [`RadioButton`]s.
+pub struct RadioPlugin;
+
+impl Plugin for RadioPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_radio_activate)
+            .add_systems(PostUpdate, radio_system.before(AnimationSystems));
+    }
+}
+
+fn on_radio_activate(
+    event: On<Activate>,
+    mut radio_query: Query<(Entity, &mut RadioButton)>,
+    group_query: Query<(), With<RadioGroup>>,
+    parent_query: Query<&ChildOf>,
+    mut commands: Commands,
+) {
+    let Ok((_, radio)) = radio_query.get(event.entity) else {
+        return;
+    };
+    if radio.checked {
+        return;
+    }
+
+    let find_group = |entity: Entity| {
+        parent_query
+            .iter_ancestors(entity)
+            .find(|&ancestor| group_query.contains(ancestor))
+    };
+    let group = find_group(event.entity);
+
+    for (entity, mut radio) in radio_query.iter_mut() {
+        if entity == event.entity {
+            radio.checked = true;
+        } else if radio.checked && group.is_some() && find_group(entity) == group {
+            radio.checked = false;
+        }
+    }
+
+    commands.trigger(ValueChange {
+        entity: event.entity,
+        value: true,
+    });
+
+    if let Some(group) = group {
+        commands.trigger(ValueChange {
+            entity: group,
+            value: event.entity,
+        });
+    }
+}
+
+pub fn radio_system(
+    mut query: Query<(Entity, &RadioButton, Option<&mut AccessibilityNode>), Changed<RadioButton>>,
+    mut commands: Commands,
+) {
+    for (entity, radio, accessible) in query.iter_mut() {
+        set_checked(
+            &mut commands,
+            entity,
+            radio.checked,
+            accessible.map(|accessible| accessible.into_inner()),
+        );
+    }
+}
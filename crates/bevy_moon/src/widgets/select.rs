@@ -0,0 +1,864 @@
+//! Dropdown selects, the options are listed in an [`Overlay`] below the control.
+//!
+//! The open list is filtered by typing, navigated with the arrow keys, `Enter` selects and `Escape` closes.
+//! It's drawn with the [`MenuStyle`].
+
+use core::{any::Any, fmt};
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, PostUpdate, Update};
+use bevy_ecs::{
+    change_detection::DetectChanges,
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    message::MessageReader,
+    observer::On,
+    query::{Changed, With},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut},
+    world::Ref,
+};
+use bevy_input::{
+    ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{FocusedInput, InputFocus};
+use bevy_math::Vec2;
+use bevy_picking::{
+    Pickable,
+    events::{Click, Pointer},
+    pointer::PointerButton,
+};
+use bevy_platform::sync::Arc;
+use bevy_text::{TextColor, TextFont};
+use taffy::{AlignItems, Dimension, Display, FlexDirection, JustifyContent, LengthPercentage};
+
+use crate::{
+    UiSystems,
+    computed::ComputedNode,
+    elements::{
+        node::{Node, ScrollPosition},
+        text::Text,
+    },
+    focus::CaptureArrowKeys,
+    interaction::{Disabled, Interaction},
+    overlay::{Overlay, OverlayAlign, OverlayPlacement},
+    style::{BorderColor, Corners, Style},
+};
+
+use super::{Activatable, Activate, PopupPresses, ValueChange, accessible_node, menu::MenuStyle};
+
+/// Triggers the [`ValueChange`] of an option on the node.
+type ChangeAction = Arc<dyn Fn(&mut Commands, Entity) + Send + Sync>;
+
+/// An option of a [`Select`], triggers a [`ValueChange`] with its value when selected.
+#[derive(Clone)]
+pub struct SelectOption {
+    pub label: String,
+    pub disabled: bool,
+    value: Arc<dyn Any + Send + Sync>,
+    change: ChangeAction,
+}
+
+impl SelectOption {
+    #[inline]
+    pub fn new<T>(label: impl Into<String>, value: T) -> Self
+    where
+        T: Clone + Send + Sync + 'static,
+    {
+        Self {
+            label: label.into(),
+            disabled: false,
+            value: Arc::new(value.clone()),
+            change: Arc::new(move |commands, entity| {
+                commands.trigger(ValueChange {
+                    entity,
+                    value: value.clone(),
+                });
+            }),
+        }
+    }
+
+    #[inline]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns the value of the option if it's a `T`.
+    #[inline]
+    pub fn value<T: Any>(&self) -> Option<&T> {
+        self.value.downcast_ref::<T>()
+    }
+
+    /// Returns `true` if the label contains the filter, ignoring the case.
+    #[inline]
+    pub fn matches(&self, filter: &str) -> bool {
+        filter.is_empty() || self.label.to_lowercase().contains(&filter.to_lowercase())
+    }
+}
+
+impl fmt::Debug for SelectOption {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SelectOption")
+            .field("label", &self.label)
+            .field("disabled", &self.disabled)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A dropdown select, opens the list of its options when activated.
+///
+/// The label of the selected option, or the placeholder, is written into the [`SelectLabel`] descendants.
+#[derive(Component, Clone, Debug)]
+#[require(
+    Activatable,
+    CaptureArrowKeys,
+    AccessibilityNode = accessible_node(Role::ComboBox)
+)]
+pub struct Select {
+    pub options: Vec<SelectOption>,
+    pub selected: Option<usize>,
+    pub placeholder: String,
+    /// The maximum height of the list, which scrolls beyond it.
+    pub max_height: f32,
+}
+
+impl Default for Select {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Select {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            options: Vec::new(),
+            selected: None,
+            placeholder: String::new(),
+            max_height: 240.0,
+        }
+    }
+
+    #[inline]
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    #[inline]
+    pub const fn selected(mut self, index: usize) -> Self {
+        self.selected = Some(index);
+        self
+    }
+
+    #[inline]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    #[inline]
+    pub const fn max_height(mut self, max_height: f32) -> Self {
+        self.max_height = max_height;
+        self
+    }
+
+    #[inline]
+    pub fn selected_option(&self) -> Option<&SelectOption> {
+        self.selected.and_then(|index| self.options.get(index))
+    }
+
+    /// Returns the value of the selected option if it's a `T`.
+    #[inline]
+    pub fn selected_value<T: Any>(&self) -> Option<&T> {
+        self.selected_option()?.value::<T>()
+    }
+
+    /// Returns the label of the selected option, or the placeholder.
+    #[inline]
+    pub fn label(&self) -> &str {
+        self.selected_option()
+            .map_or(self.placeholder.as_str(), |option| option.label.as_str())
+    }
+}
+
+/// A [`Text`] descendant of a [`Select`] which shows the selected label.
+#[derive(Component, Clone, Copy, Debug, Default)]
+#[require(Text)]
+pub struct SelectLabel;
+
+/// The popup of the open select.
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SelectPopup {
+    /// The node of the [`Select`].
+    pub select: Entity,
+}
+
+/// An option of the [`SelectPopup`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct SelectOptionNode {
+    pub index: usize,
+}
+
+/// The open select.
+#[derive(Resource, Debug, Default)]
+pub struct ActiveSelect {
+    select: Option<Entity>,
+    popup: Option<Entity>,
+    /// The scrolled list of the options.
+    list: Option<Entity>,
+    /// The node of the typed filter.
+    filter_node: Option<Entity>,
+    options: Vec<SelectOption>,
+    nodes: Vec<Entity>,
+    filter: String,
+    highlighted: Option<usize>,
+    /// Scrolls the highlighted option into view once it's laid out.
+    reveal: bool,
+    /// The focus before the select was opened, restored when it closes.
+    previous_focus: Option<Entity>,
+}
+
+impl ActiveSelect {
+    /// Returns the node whose list is open.
+    #[inline]
+    pub fn select(&self) -> Option<Entity> {
+        self.select
+    }
+
+    #[inline]
+    pub fn is_open(&self) -> bool {
+        self.select.is_some()
+    }
+
+    /// Returns the typed filter.
+    #[inline]
+    pub fn filter(&self) -> &str {
+        &self.filter
+    }
+
+    fn open(
+        &mut self,
+        commands: &mut Commands,
+        style: &MenuStyle,
+        input_focus: &mut InputFocus,
+        entity: Entity,
+        select: &Select,
+        width: f32,
+    ) {
+        self.close(commands, input_focus);
+
+        let popup = commands
+            .spawn((
+                Overlay::new(entity)
+                    .placement(OverlayPlacement::Bottom)
+                    .align(OverlayAlign::Start)
+                    .gap(2.0),
+                SelectPopup { select: entity },
+                Style::from(taffy::Style {
+                    flex_direction: FlexDirection::Column,
+                    min_size: taffy::Size {
+                        width: Dimension::length(width),
+                        height: Dimension::auto(),
+                    },
+                    padding: taffy::Rect::length(style.padding),
+                    border: taffy::Rect::length(1.0),
+                    ..Default::default()
+                })
+                .background(style.background)
+                .border_color(BorderColor::all(style.border_color))
+                .corner_radii(Corners::all(style.corner_radius)),
+            ))
+            .id();
+
+        let filter_node = commands
+            .spawn((
+                Text::default(),
+                TextFont::from_font_size(style.font_size),
+                TextColor(style.shortcut_color),
+                ChildOf(popup),
+                Pickable::IGNORE,
+                Style::from(taffy::Style {
+                    display: Display::None,
+                    padding: taffy::Rect {
+                        left: LengthPercentage::length(style.item_padding.x),
+                        right: LengthPercentage::length(style.item_padding.x),
+                        top: LengthPercentage::length(style.item_padding.y),
+                        bottom: LengthPercentage::length(style.item_padding.y),
+                    },
+                    ..Default::default()
+                }),
+            ))
+            .id();
+
+        let list = commands
+            .spawn((
+                Node,
+                ChildOf(popup),
+                ScrollPosition::default(),
+                AccessibilityNode::from(accesskit::Node::new(Role::ListBox)),
+                Style::from(taffy::Style {
+                    flex_direction: FlexDirection::Column,
+                    max_size: taffy::Size {
+                        width: Dimension::auto(),
+                        height: Dimension::length(select.max_height),
+                    },
+                    overflow: taffy::Point {
+                        x: taffy::Overflow::Clip,
+                        y: taffy::Overflow::Scroll,
+                    },
+                    ..Default::default()
+                }),
+            ))
+            .id();
+
+        self.nodes = select
+            .options
+            .iter()
+            .enumerate()
+            .map(|(index, option)| {
+                spawn_option(
+                    commands,
+                    style,
+                    list,
+                    index,
+                    option,
+                    select.selected == Some(index),
+                )
+            })
+            .collect();
+
+        self.previous_focus = input_focus.get();
+        input_focus.set(popup);
+
+        self.select = Some(entity);
+        self.popup = Some(popup);
+        self.list = Some(list);
+        self.filter_node = Some(filter_node);
+        self.options = select.options.clone();
+        self.filter.clear();
+        self.highlighted = select
+            .selected
+            .filter(|&index| self.is_selectable(index))
+            .or_else(|| self.first_selectable());
+        self.reveal = true;
+    }
+
+    fn close(&mut self, commands: &mut Commands, input_focus: &mut InputFocus) {
+        let Some(select) = self.select.take() else {
+            return;
+        };
+
+        if let Some(popup) = self.popup.take() {
+            commands.entity(popup).try_despawn();
+
+            if input_focus.get() == Some(popup) {
+                input_focus.set(select);
+            }
+        }
+
+        self.list = None;
+        self.filter_node = None;
+        self.options.clear();
+        self.nodes.clear();
+        self.filter.clear();
+        self.highlighted = None;
+        self.reveal = false;
+        self.previous_focus = None;
+    }
+
+    /// Returns `true` if the option matches the filter and is enabled.
+    #[inline]
+    fn is_selectable(&self, index: usize) -> bool {
+        self.options
+            .get(index)
+            .is_some_and(|option| !option.disabled && option.matches(&self.filter))
+    }
+
+    #[inline]
+    fn first_selectable(&self) -> Option<usize> {
+        (0..self.options.len()).find(|&index| self.is_selectable(index))
+    }
+
+    #[inline]
+    fn last_selectable(&self) -> Option<usize> {
+        (0..self.options.len())
+            .rev()
+            .find(|&index| self.is_selectable(index))
+    }
+
+    /// Moves the highlight to the next selectable option in the direction, stops at the ends.
+    fn move_highlight(&mut self, forward: bool) {
+        let next = match (self.highlighted, forward) {
+            (Some(index), true) => (index + 1..self.options.len()).find(|&i| self.is_selectable(i)),
+            (Some(index), false) => (0..index).rev().find(|&i| self.is_selectable(i)),
+            (None, true) => self.first_selectable(),
+            (None, false) => self.last_selectable(),
+        };
+
+        if next.is_some() {
+            self.highlighted = next;
+            self.reveal = true;
+        }
+    }
+
+    fn set_filter(&mut self, filter: String) {
+        self.filter = filter;
+
+        if !self
+            .highlighted
+            .is_some_and(|index| self.is_selectable(index))
+        {
+            self.highlighted = self.first_selectable();
+        }
+        self.reveal = true;
+    }
+
+    /// Selects an option and closes the list.
+    fn choose(
+        &mut self,
+        commands: &mut Commands,
+        input_focus: &mut InputFocus,
+        select_query: &mut Query<&mut Select>,
+        index: usize,
+    ) {
+        let Some(entity) = self.select else {
+            return;
+        };
+        if !self.is_selectable(index) {
+            return;
+        }
+
+        let change = self.options[index].change.clone();
+        self.close(commands, input_focus);
+
+        let Ok(mut select) = select_query.get_mut(entity) else {
+            return;
+        };
+        if select.selected != Some(index) {
+            select.selected = Some(index);
+            change(commands, entity);
+        }
+    }
+}
+
+/// A plugin that adds [`Select`]s.
+pub struct SelectPlugin;
+
+impl Plugin for SelectPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<MenuStyle>()
+            .init_resource::<ActiveSelect>()
+            .add_observer(on_select_activate)
+            .add_observer(on_select_key)
+            .add_observer(on_select_popup_key)
+            .add_systems(
+                Update,
+                (
+                    select_close_system,
+                    select_hover_system,
+                    select_click_system,
+                    select_popup_system,
+                )
+                    .chain(),
+            )
+            .add_systems(PostUpdate, select_label_system.before(UiSystems::Prepare));
+    }
+}
+
+fn on_select_activate(
+    event: On<Activate>,
+    style: Res<MenuStyle>,
+    select_query: Query<(&Select, &ComputedNode)>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveSelect>,
+    mut commands: Commands,
+) {
+    let Ok((select, computed_node)) = select_query.get(event.entity) else {
+        return;
+    };
+
+    if active.select == Some(event.entity) {
+        active.close(&mut commands, &mut input_focus);
+        return;
+    }
+
+    active.open(
+        &mut commands,
+        &style,
+        &mut input_focus,
+        event.entity,
+        select,
+        computed_node.size.x,
+    );
+}
+
+/// Opens the focused select with the arrow keys.
+fn on_select_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    style: Res<MenuStyle>,
+    select_query: Query<(&Select, &ComputedNode, &Interaction)>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveSelect>,
+    mut commands: Commands,
+) {
+    let entity = event.focused_entity;
+    let Ok((select, computed_node, interaction)) = select_query.get(entity) else {
+        return;
+    };
+    if event.input.state != ButtonState::Pressed || interaction.disabled {
+        return;
+    }
+    if !matches!(event.input.key_code, KeyCode::ArrowDown | KeyCode::ArrowUp) {
+        return;
+    }
+
+    event.propagate(false);
+
+    active.open(
+        &mut commands,
+        &style,
+        &mut input_focus,
+        entity,
+        select,
+        computed_node.size.x,
+    );
+}
+
+fn on_select_popup_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    popup_query: Query<(), With<SelectPopup>>,
+    mut select_query: Query<&mut Select>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveSelect>,
+    mut commands: Commands,
+) {
+    if !popup_query.contains(event.focused_entity) || event.input.state != ButtonState::Pressed {
+        return;
+    }
+
+    match event.input.key_code {
+        KeyCode::ArrowDown => active.move_highlight(true),
+        KeyCode::ArrowUp => active.move_highlight(false),
+        KeyCode::Home => {
+            active.highlighted = active.first_selectable();
+            active.reveal = true;
+        }
+        KeyCode::End => {
+            active.highlighted = active.last_selectable();
+            active.reveal = true;
+        }
+        KeyCode::Enter | KeyCode::NumpadEnter => {
+            if let Some(index) = active.highlighted {
+                active.choose(&mut commands, &mut input_focus, &mut select_query, index);
+            }
+        }
+        KeyCode::Escape | KeyCode::Tab => active.close(&mut commands, &mut input_focus),
+        KeyCode::Backspace => {
+            let mut filter = active.filter.clone();
+            if filter.pop().is_none() {
+                return;
+            }
+            active.set_filter(filter);
+        }
+        _ => {
+            let Some(text) = event
+                .input
+                .text
+                .as_ref()
+                .filter(|text| !text.chars().any(char::is_control))
+            else {
+                return;
+            };
+            let filter = format!("{}{text}", active.filter);
+            active.set_filter(filter);
+        }
+    }
+
+    event.propagate(false);
+}
+
+/// Closes the open select with a press outside, or when the select is removed.
+pub fn select_close_system(
+    mut presses: PopupPresses<SelectPopup>,
+    select_query: Query<&Interaction, With<Select>>,
+    parent_query: Query<&ChildOf>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveSelect>,
+    mut commands: Commands,
+) {
+    let Some(select) = active.select else {
+        presses.clear();
+        return;
+    };
+
+    let mut pressed = false;
+    let mut pressed_inside = false;
+
+    for (press, inside) in presses.read() {
+        pressed = true;
+
+        // A press on the select toggles it when activated.
+        if inside
+            || core::iter::once(press.entity)
+                .chain(parent_query.iter_ancestors(press.entity))
+                .any(|entity| entity == select)
+        {
+            pressed_inside = true;
+        }
+    }
+
+    let mouse_pressed = presses.mouse_just_pressed();
+    let select_removed = select_query
+        .get(select)
+        .ok()
+        .is_none_or(|interaction| interaction.disabled);
+
+    if select_removed || (!pressed_inside && (pressed || mouse_pressed)) {
+        let previous_focus = active.previous_focus;
+        active.close(&mut commands, &mut input_focus);
+
+        // A press outside keeps the focus it moved to, otherwise restores the previous one.
+        if select_removed && input_focus.get().is_none_or(|focus| focus == select) {
+            match previous_focus {
+                Some(previous) => input_focus.set(previous),
+                None => input_focus.clear(),
+            }
+        }
+    }
+}
+
+/// Highlights the hovered options.
+pub fn select_hover_system(
+    option_query: Query<(Entity, &SelectOptionNode, Ref<Interaction>)>,
+    mut active: ResMut<ActiveSelect>,
+) {
+    for (entity, option, interaction) in option_query.iter() {
+        if !interaction.is_changed() || !interaction.hovered || interaction.disabled {
+            continue;
+        }
+        if active.nodes.get(option.index) != Some(&entity) {
+            continue;
+        }
+
+        if active.highlighted != Some(option.index) {
+            active.highlighted = Some(option.index);
+        }
+    }
+}
+
+/// Selects the clicked options.
+pub fn select_click_system(
+    mut click_reader: MessageReader<Pointer<Click>>,
+    option_query: Query<&SelectOptionNode>,
+    mut select_query: Query<&mut Select>,
+    mut input_focus: ResMut<InputFocus>,
+    mut active: ResMut<ActiveSelect>,
+    mut commands: Commands,
+) {
+    for click in click_reader.read() {
+        if click.button != PointerButton::Primary {
+            continue;
+        }
+        let Ok(option) = option_query.get(click.entity) else {
+            continue;
+        };
+        if active.nodes.get(option.index) != Some(&click.entity) {
+            continue;
+        }
+
+        active.choose(
+            &mut commands,
+            &mut input_focus,
+            &mut select_query,
+            option.index,
+        );
+    }
+}
+
+/// Filters and paints the options, and scrolls the highlighted option into view.
+pub fn select_popup_system(
+    menu_style: Res<MenuStyle>,
+    mut active: ResMut<ActiveSelect>,
+    mut style_query: Query<&mut Style>,
+    mut text_query: Query<&mut Text>,
+    mut list_query: Query<(&mut ScrollPosition, &ComputedNode)>,
+    computed_query: Query<&ComputedNode>,
+) {
+    if !active.is_changed() || !active.is_open() {
+        return;
+    }
+
+    if let Some(filter_node) = active.filter_node {
+        if let Ok(mut text) = text_query.get_mut(filter_node)
+            && text.0 != active.filter
+        {
+            text.0.clone_from(&active.filter);
+        }
+
+        if let Ok(mut style) = style_query.get_mut(filter_node) {
+            let display = if active.filter.is_empty() {
+                Display::None
+            } else {
+                Display::Flex
+            };
+            if style.get_ref().display != display {
+                style.get_mut().display = display;
+            }
+        }
+    }
+
+    for (index, &node) in active.nodes.iter().enumerate() {
+        let Ok(mut style) = style_query.get_mut(node) else {
+            continue;
+        };
+
+        let display = if active.options[index].matches(&active.filter) {
+            Display::Flex
+        } else {
+            Display::None
+        };
+        if style.get_ref().display != display {
+            style.get_mut().display = display;
+        }
+
+        let background = (active.highlighted == Some(index)).then_some(menu_style.highlight);
+        if style.background != background {
+            style.background = background;
+        }
+    }
+
+    if !active.reveal {
+        return;
+    }
+
+    let (Some(list), Some(index)) = (active.list, active.highlighted) else {
+        active.reveal = false;
+        return;
+    };
+    let Ok(option_node) = computed_query.get(active.nodes[index]) else {
+        return;
+    };
+    let Ok((mut scroll_position, list_node)) = list_query.get_mut(list) else {
+        return;
+    };
+
+    // Waits for the layout of the list.
+    if option_node.is_empty() || list_node.is_empty() {
+        return;
+    }
+
+    let top = option_node.location.y;
+    let bottom = top + option_node.size.y;
+    let view = list_node.size.y;
+
+    let mut offset = scroll_position.0;
+    if top < offset.y {
+        offset.y = top;
+    } else if bottom > offset.y + view {
+        offset.y = bottom - view;
+    }
+    offset = offset.clamp(Vec2::ZERO, list_node.max_scroll());
+
+    if scroll_position.0 != offset {
+        scroll_position.0 = offset;
+    }
+
+    active.reveal = false;
+}
+
+/// Shows the selected labels, and updates the accessibility nodes.
+pub fn select_label_system(
+    mut select_query: Query<(Entity, &Select, Option<&mut AccessibilityNode>), Changed<Select>>,
+    children_query: Query<&Children>,
+    mut label_query: Query<&mut Text, With<SelectLabel>>,
+) {
+    for (entity, select, accessible) in select_query.iter_mut() {
+        let label = select.label();
+
+        if let Some(mut accessible) = accessible {
+            accessible.set_value(label);
+        }
+
+        let mut labels = label_query.iter_many_mut(children_query.iter_descendants(entity));
+        while let Some(mut text) = labels.fetch_next() {
+            if text.0 != label {
+                text.0 = label.to_owned();
+            }
+        }
+    }
+}
+
+fn spawn_option(
+    commands: &mut Commands,
+    style: &MenuStyle,
+    list: Entity,
+    index: usize,
+    option: &SelectOption,
+    selected: bool,
+) -> Entity {
+    let mut accessible = accesskit::Node::new(Role::ListBoxOption);
+    accessible.set_label(option.label.as_str());
+    accessible.set_selected(selected);
+
+    let text_color = if option.disabled {
+        style.disabled_color
+    } else {
+        style.text_color
+    };
+
+    let mut entity = commands.spawn((
+        Node,
+        ChildOf(list),
+        SelectOptionNode { index },
+        Interaction::default(),
+        AccessibilityNode::from(accessible),
+        Style::from(taffy::Style {
+            flex_direction: FlexDirection::Row,
+            justify_content: Some(JustifyContent::SpaceBetween),
+            align_items: Some(AlignItems::Center),
+            flex_shrink: 0.0,
+            gap: taffy::Size {
+                width: LengthPercentage::length(24.0),
+                height: LengthPercentage::length(0.0),
+            },
+            padding: taffy::Rect {
+                left: LengthPercentage::length(style.item_padding.x),
+                right: LengthPercentage::length(style.item_padding.x),
+                top: LengthPercentage::length(style.item_padding.y),
+                bottom: LengthPercentage::length(style.item_padding.y),
+            },
+            ..Default::default()
+        })
+        .corner_radii(Corners::all(style.corner_radius)),
+    ));
+
+    if option.disabled {
+        entity.insert(Disabled);
+    }
+
+    entity.with_children(|parent| {
+        parent.spawn((
+            Text::new(option.label.as_str()),
+            TextFont::from_font_size(style.font_size),
+            TextColor(text_color),
+            Pickable::IGNORE,
+        ));
+
+        if selected {
+            parent.spawn((
+                Text::new("✓"),
+                TextFont::from_font_size(style.font_size),
+                TextColor(style.shortcut_color),
+                Pickable::IGNORE,
+            ));
+        }
+    });
+
+    entity.id()
+}
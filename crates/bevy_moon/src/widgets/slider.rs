@@ -0,0 +1,561 @@
+//! Sliders, and the numeric ranges shared with the drag values.
+
+use accesskit::{Action, ActionData, Role};
+use bevy_a11y::{AccessibilityNode, ActionRequest};
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate, Update};
+use bevy_camera::Camera;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::Children,
+    message::MessageReader,
+    observer::On,
+    query::{Changed, With},
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query},
+};
+use bevy_input::{
+    ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{FocusedInput, tab_navigation::TabIndex};
+use bevy_math::Vec2;
+use bevy_picking::{
+    events::{Drag, DragEnd, Pointer, Press, Release},
+    pointer::PointerButton,
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_transform::components::GlobalTransform;
+use taffy::{LengthPercentageAuto, Position};
+
+use crate::{
+    computed::ComputedNode, elements::node::Node, focus::CaptureArrowKeys,
+    interaction::Interaction, picking::pointer_local_point, style::Style,
+};
+
+use super::ValueChange;
+
+/// How a value is mapped onto a slider track, or scrubbed by a drag value.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum ValueScale {
+    #[default]
+    Linear,
+    /// Equal distances multiply the value, requires a positive range.
+    Logarithmic,
+}
+
+/// The range of a numeric widget.
+#[derive(Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub struct ValueRange {
+    pub min: f32,
+    pub max: f32,
+    /// Snaps the values to the multiples of the step from `min`.
+    pub step: Option<f32>,
+    pub scale: ValueScale,
+}
+
+impl Default for ValueRange {
+    fn default() -> Self {
+        Self::new(0.0, 1.0)
+    }
+}
+
+impl ValueRange {
+    pub const UNBOUNDED: Self = Self::new(f32::NEG_INFINITY, f32::INFINITY);
+
+    #[inline]
+    pub const fn new(min: f32, max: f32) -> Self {
+        Self {
+            min,
+            max,
+            step: None,
+            scale: ValueScale::Linear,
+        }
+    }
+
+    #[inline]
+    pub const fn step(mut self, step: f32) -> Self {
+        self.step = Some(step);
+        self
+    }
+
+    #[inline]
+    pub const fn scale(mut self, scale: ValueScale) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    #[inline]
+    pub const fn logarithmic(self) -> Self {
+        self.scale(ValueScale::Logarithmic)
+    }
+
+    /// Returns `true` if both ends of the range are finite.
+    #[inline]
+    pub fn is_bounded(&self) -> bool {
+        self.min.is_finite() && self.max.is_finite() && self.min < self.max
+    }
+
+    /// Returns `true` if the logarithmic scale applies, which falls back to linear for non-positive ranges.
+    #[inline]
+    pub fn is_logarithmic(&self) -> bool {
+        self.scale == ValueScale::Logarithmic && self.is_bounded() && self.min > 0.0
+    }
+
+    pub fn clamp(&self, value: f32) -> f32 {
+        if value.is_nan() {
+            return self.min.max(0.0).min(self.max);
+        }
+        value.max(self.min).min(self.max)
+    }
+
+    /// Snaps the value to the step and clamps it into the range.
+    pub fn snap(&self, value: f32) -> f32 {
+        let value = match self.step.filter(|step| *step > 0.0) {
+            Some(step) => {
+                let origin = if self.min.is_finite() { self.min } else { 0.0 };
+                origin + ((value - origin) / step).round() * step
+            }
+            None => value,
+        };
+        self.clamp(value)
+    }
+
+    /// Maps the value into `0.0..=1.0` along the range.
+    pub fn normalize(&self, value: f32) -> f32 {
+        if !self.is_bounded() {
+            return 0.0;
+        }
+
+        let t = if self.is_logarithmic() {
+            (value / self.min).ln() / (self.max / self.min).ln()
+        } else {
+            (value - self.min) / (self.max - self.min)
+        };
+
+        if t.is_nan() { 0.0 } else { t.clamp(0.0, 1.0) }
+    }
+
+    /// Maps `0.0..=1.0` back into a value of the range, the reverse of [`ValueRange::normalize`].
+    pub fn denormalize(&self, t: f32) -> f32 {
+        let t = t.clamp(0.0, 1.0);
+
+        if self.is_logarithmic() {
+            self.min * (self.max / self.min).powf(t)
+        } else {
+            self.min + (self.max - self.min) * t
+        }
+    }
+
+    /// Moves the value by a number of steps, a hundredth of the range without a step.
+    pub fn step_by(&self, value: f32, steps: f32) -> f32 {
+        match self.step.filter(|step| *step > 0.0) {
+            Some(step) => self.snap(value + step * steps),
+            None if self.is_bounded() => self.denormalize(self.normalize(value) + 0.01 * steps),
+            None => self.clamp(value + steps),
+        }
+    }
+}
+
+/// Formats a value with a number of decimal places.
+#[inline]
+pub fn format_value(value: f32, precision: usize) -> String {
+    format!("{value:.precision$}")
+}
+
+/// The direction of a [`Slider`] track.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub enum SliderOrientation {
+    /// The minimum is on the left.
+    #[default]
+    Horizontal,
+    /// The minimum is at the bottom.
+    Vertical,
+}
+
+/// A slider, the node is the track and a [`SliderThumb`] child marks the value.
+///
+/// Pressing the track jumps to the pointer, dragging follows it.
+/// While focused, the arrow keys move the value by a step, `PageUp` and `PageDown` by ten steps,
+/// `Home` and `End` to the ends. Triggers a [`ValueChange<f32>`] when changed by the user.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(
+    Node,
+    Interaction,
+    TabIndex,
+    CaptureArrowKeys,
+    SliderState,
+    AccessibilityNode = numeric_node(Role::Slider)
+)]
+pub struct Slider {
+    pub value: f32,
+    pub range: ValueRange,
+    pub orientation: SliderOrientation,
+    /// The decimal places of the value reported to assistive technologies.
+    pub precision: usize,
+}
+
+impl Default for Slider {
+    fn default() -> Self {
+        Self::new(0.0, ValueRange::default())
+    }
+}
+
+impl Slider {
+    #[inline]
+    pub const fn new(value: f32, range: ValueRange) -> Self {
+        Self {
+            value,
+            range,
+            orientation: SliderOrientation::Horizontal,
+            precision: 2,
+        }
+    }
+
+    #[inline]
+    pub const fn orientation(mut self, orientation: SliderOrientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    #[inline]
+    pub const fn vertical(self) -> Self {
+        self.orientation(SliderOrientation::Vertical)
+    }
+
+    #[inline]
+    pub const fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    /// Returns the value at the node-local point, from the center of the node.
+    pub fn value_at(&self, local_point: Vec2, size: Vec2) -> f32 {
+        let t = match self.orientation {
+            SliderOrientation::Horizontal => (local_point.x + 0.5 * size.x) / size.x,
+            SliderOrientation::Vertical => (local_point.y + 0.5 * size.y) / size.y,
+        };
+        let t = if t.is_finite() { t } else { 0.0 };
+
+        self.range.snap(self.range.denormalize(t))
+    }
+}
+
+/// The thumb of a [`Slider`], absolutely positioned at the value along the track.
+///
+/// The inset is a percent of the track, use a negative margin of half the thumb size to center it.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Node)]
+pub struct SliderThumb;
+
+/// The pointer dragging a [`Slider`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct SliderState {
+    /// The camera of the current drag.
+    camera: Option<Entity>,
+}
+
+impl SliderState {
+    /// Returns `true` while the slider is pressed or dragged.
+    #[inline]
+    pub fn is_dragging(&self) -> bool {
+        self.camera.is_some()
+    }
+}
+
+/// A plugin that adds [`Slider`]s.
+pub struct SliderPlugin;
+
+impl Plugin for SliderPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_observer(on_slider_press)
+            .add_observer(on_slider_drag)
+            .add_observer(on_slider_release)
+            .add_observer(on_slider_drag_end)
+            .add_observer(on_slider_key)
+            .add_systems(Update, slider_action_system)
+            .add_systems(PostUpdate, slider_system.before(AnimationSystems));
+    }
+}
+
+/// Makes an accessibility node of the role which can be incremented and decremented.
+pub(super) fn numeric_node(role: Role) -> AccessibilityNode {
+    let mut node = accesskit::Node::new(role);
+    node.add_action(Action::Increment);
+    node.add_action(Action::Decrement);
+    node.add_action(Action::SetValue);
+    AccessibilityNode::from(node)
+}
+
+/// Updates the numeric properties of an accessibility node.
+pub(super) fn set_numeric(
+    accessible: &mut AccessibilityNode,
+    value: f32,
+    range: &ValueRange,
+    precision: usize,
+) {
+    accessible.set_numeric_value(value as f64);
+    accessible.set_value(format_value(value, precision));
+
+    if range.min.is_finite() {
+        accessible.set_min_numeric_value(range.min as f64);
+    } else {
+        accessible.clear_min_numeric_value();
+    }
+    if range.max.is_finite() {
+        accessible.set_max_numeric_value(range.max as f64);
+    } else {
+        accessible.clear_max_numeric_value();
+    }
+    if let Some(step) = range.step {
+        accessible.set_numeric_value_step(step as f64);
+    } else {
+        accessible.clear_numeric_value_step();
+    }
+}
+
+/// Sets the value of a slider, triggers a [`ValueChange<f32>`] if it's changed.
+fn change_slider(commands: &mut Commands, entity: Entity, slider: &mut Slider, value: f32) {
+    if slider.value == value {
+        return;
+    }
+
+    slider.value = value;
+
+    commands.trigger(ValueChange { entity, value });
+}
+
+fn pointer_value(
+    cameras: &Query<(&Camera, &GlobalTransform)>,
+    camera: Entity,
+    position: Vec2,
+    slider: &Slider,
+    transform: &GlobalTransform,
+    computed_node: &ComputedNode,
+) -> Option<f32> {
+    let local_point = pointer_local_point(cameras, camera, position, transform)?;
+
+    Some(slider.value_at(local_point, computed_node.size))
+}
+
+fn on_slider_press(
+    mut event: On<Pointer<Press>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut slider_query: Query<(
+        &mut Slider,
+        &mut SliderState,
+        &Interaction,
+        &GlobalTransform,
+        &ComputedNode,
+    )>,
+    mut commands: Commands,
+) {
+    let Ok((mut slider, mut state, interaction, transform, computed_node)) =
+        slider_query.get_mut(event.entity)
+    else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    if interaction.disabled {
+        state.camera = None;
+        return;
+    }
+
+    state.camera = Some(event.hit.camera);
+
+    if let Some(value) = pointer_value(
+        &cameras,
+        event.hit.camera,
+        event.pointer_location.position,
+        &slider,
+        transform,
+        computed_node,
+    ) {
+        change_slider(&mut commands, event.entity, &mut slider, value);
+    }
+}
+
+fn on_slider_drag(
+    mut event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    mut slider_query: Query<(
+        &mut Slider,
+        &SliderState,
+        &Interaction,
+        &GlobalTransform,
+        &ComputedNode,
+    )>,
+    mut commands: Commands,
+) {
+    let Ok((mut slider, state, interaction, transform, computed_node)) =
+        slider_query.get_mut(event.entity)
+    else {
+        return;
+    };
+
+    let Some(camera) = state.camera.filter(|_| !interaction.disabled) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    if let Some(value) = pointer_value(
+        &cameras,
+        camera,
+        event.pointer_location.position,
+        &slider,
+        transform,
+        computed_node,
+    ) {
+        change_slider(&mut commands, event.entity, &mut slider, value);
+    }
+}
+
+/// Ends a press without a drag.
+fn on_slider_release(event: On<Pointer<Release>>, mut state_query: Query<&mut SliderState>) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    if let Ok(mut state) = state_query.get_mut(event.entity)
+        && state.camera.is_some()
+    {
+        state.camera = None;
+    }
+}
+
+/// Ends a drag, also when the pointer is released outside of the slider.
+fn on_slider_drag_end(event: On<Pointer<DragEnd>>, mut state_query: Query<&mut SliderState>) {
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    if let Ok(mut state) = state_query.get_mut(event.entity)
+        && state.camera.is_some()
+    {
+        state.camera = None;
+    }
+}
+
+fn on_slider_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    mut slider_query: Query<(&mut Slider, &Interaction)>,
+    mut commands: Commands,
+) {
+    let entity = event.focused_entity;
+    let Ok((mut slider, interaction)) = slider_query.get_mut(entity) else {
+        return;
+    };
+    if event.input.state != ButtonState::Pressed || interaction.disabled {
+        return;
+    }
+
+    let range = slider.range;
+    let value = match event.input.key_code {
+        KeyCode::ArrowRight | KeyCode::ArrowUp => range.step_by(slider.value, 1.0),
+        KeyCode::ArrowLeft | KeyCode::ArrowDown => range.step_by(slider.value, -1.0),
+        KeyCode::PageUp => range.step_by(slider.value, 10.0),
+        KeyCode::PageDown => range.step_by(slider.value, -10.0),
+        KeyCode::Home if range.min.is_finite() => range.min,
+        KeyCode::End if range.max.is_finite() => range.max,
+        _ => return,
+    };
+
+    event.propagate(false);
+
+    change_slider(&mut commands, entity, &mut slider, value);
+}
+
+/// Changes the sliders by the actions of assistive technologies.
+fn slider_action_system(
+    mut requests: MessageReader<ActionRequest>,
+    mut slider_query: Query<(&mut Slider, &Interaction)>,
+    mut commands: Commands,
+) {
+    for request in requests.read() {
+        let Some(entity) = Entity::try_from_bits(request.target.0) else {
+            continue;
+        };
+        let Ok((mut slider, interaction)) = slider_query.get_mut(entity) else {
+            continue;
+        };
+        if interaction.disabled {
+            continue;
+        }
+
+        let range = slider.range;
+        let value = match (request.action, &request.data) {
+            (Action::Increment, _) => range.step_by(slider.value, 1.0),
+            (Action::Decrement, _) => range.step_by(slider.value, -1.0),
+            (Action::SetValue, Some(ActionData::NumericValue(value))) => range.snap(*value as f32),
+            _ => continue,
+        };
+
+        change_slider(&mut commands, entity, &mut slider, value);
+    }
+}
+
+type SliderData = (
+    &'static Slider,
+    Option<&'static Children>,
+    Option<&'static mut AccessibilityNode>,
+);
+
+/// Moves the thumbs to the values, and updates the accessibility nodes.
+pub fn slider_system(
+    mut slider_query: Query<SliderData, Changed<Slider>>,
+    mut thumb_query: Query<&mut Style, With<SliderThumb>>,
+) {
+    for (slider, children, accessible) in slider_query.iter_mut() {
+        if let Some(mut accessible) = accessible {
+            set_numeric(
+                &mut accessible,
+                slider.value,
+                &slider.range,
+                slider.precision,
+            );
+            accessible.set_orientation(match slider.orientation {
+                SliderOrientation::Horizontal => accesskit::Orientation::Horizontal,
+                SliderOrientation::Vertical => accesskit::Orientation::Vertical,
+            });
+        }
+
+        let percent = slider.range.normalize(slider.value);
+        let mut inset = taffy::Rect {
+            left: LengthPercentageAuto::auto(),
+            right: LengthPercentageAuto::auto(),
+            top: LengthPercentageAuto::auto(),
+            bottom: LengthPercentageAuto::auto(),
+        };
+        match slider.orientation {
+            SliderOrientation::Horizontal => inset.left = LengthPercentageAuto::percent(percent),
+            SliderOrientation::Vertical => inset.bottom = LengthPercentageAuto::percent(percent),
+        }
+
+        let mut thumbs = thumb_query.iter_many_mut(children.into_iter().flatten());
+        while let Some(mut style) = thumbs.fetch_next() {
+            let inner = style.get_ref();
+            if inner.position != Position::Absolute || inner.inset != inset {
+                let inner = style.get_mut();
+                inner.position = Position::Absolute;
+                inner.inset = inset;
+            }
+        }
+    }
+}
@@ -0,0 +1,472 @@
+//! Splitters, resizable panes separated by draggable handles.
+//!
+//! The panes are laid out by their flex-basis, a fraction of the splitter. The handles are
+//! absolutely positioned over the boundaries, so they don't take space from the panes.
+
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::{ChildOf, Children},
+    observer::On,
+    query::With,
+    reflect::ReflectComponent,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res},
+};
+use bevy_picking::{
+    events::{Drag, Pointer, Press},
+    pointer::PointerButton,
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::SystemCursorIcon;
+use taffy::{Dimension, FlexDirection, LengthPercentageAuto, Position};
+
+use crate::{
+    computed::ComputedNode,
+    cursor::HoverCursor,
+    elements::node::{Node, ZIndex},
+    interaction::Interaction,
+    picking::pointer_local_point,
+    states::{StyleStates, StyleVariant},
+    style::Style,
+};
+
+use super::ValueChange;
+
+/// The direction of the panes of a [`Splitter`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub enum SplitDirection {
+    /// The panes are side by side, from left to right.
+    #[default]
+    Horizontal,
+    /// The panes are stacked, from top to bottom.
+    Vertical,
+}
+
+/// A splitter, the [`SplitPane`] children share its size by their fractions.
+///
+/// Dragging a handle moves the boundary of the two panes around it,
+/// triggers a [`ValueChange<Vec<f32>>`] with the new fractions.
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Node)]
+pub struct Splitter {
+    pub direction: SplitDirection,
+    /// The fractions of the panes, normalized to sum to one.
+    ///
+    /// Missing fractions are filled with the average of the others.
+    pub fractions: Vec<f32>,
+    /// The minimum size of the panes along the direction.
+    pub min_pane_size: f32,
+    /// The thickness of the grab region of the handles.
+    pub handle_size: f32,
+}
+
+impl Default for Splitter {
+    fn default() -> Self {
+        Self::new(SplitDirection::Horizontal)
+    }
+}
+
+impl Splitter {
+    #[inline]
+    pub const fn new(direction: SplitDirection) -> Self {
+        Self {
+            direction,
+            fractions: Vec::new(),
+            min_pane_size: 32.0,
+            handle_size: 6.0,
+        }
+    }
+
+    #[inline]
+    pub const fn horizontal() -> Self {
+        Self::new(SplitDirection::Horizontal)
+    }
+
+    #[inline]
+    pub const fn vertical() -> Self {
+        Self::new(SplitDirection::Vertical)
+    }
+
+    #[inline]
+    pub fn fractions(mut self, fractions: impl Into<Vec<f32>>) -> Self {
+        self.fractions = fractions.into();
+        self
+    }
+
+    #[inline]
+    pub const fn min_pane_size(mut self, size: f32) -> Self {
+        self.min_pane_size = size;
+        self
+    }
+
+    #[inline]
+    pub const fn handle_size(mut self, size: f32) -> Self {
+        self.handle_size = size;
+        self
+    }
+
+    /// Returns the persistent state of the splitter.
+    #[inline]
+    pub fn layout(&self) -> SplitterLayout {
+        SplitterLayout {
+            direction: self.direction,
+            fractions: self.fractions.clone(),
+        }
+    }
+
+    /// Restores the direction and the fractions of the panes.
+    #[inline]
+    pub fn apply_layout(&mut self, layout: &SplitterLayout) {
+        self.direction = layout.direction;
+        self.fractions.clone_from(&layout.fractions);
+    }
+}
+
+/// The persistent state of a [`Splitter`].
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct SplitterLayout {
+    pub direction: SplitDirection,
+    pub fractions: Vec<f32>,
+}
+
+/// A pane of a [`Splitter`], its flex-basis is managed by the splitter.
+#[derive(Component, Clone, Copy, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+#[require(Node)]
+pub struct SplitPane;
+
+/// The handle between the panes `index` and `index + 1` of a [`Splitter`].
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Node, Interaction)]
+pub struct SplitterHandle {
+    pub splitter: Entity,
+    pub index: usize,
+    /// The camera of the current drag.
+    camera: Option<Entity>,
+}
+
+/// The look of the splitter handles.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct SplitterStyle {
+    pub handle_color: Color,
+    pub hovered_color: Color,
+}
+
+impl Default for SplitterStyle {
+    fn default() -> Self {
+        Self {
+            handle_color: Color::NONE,
+            hovered_color: Color::srgba(0.3, 0.6, 1.0, 0.6),
+        }
+    }
+}
+
+/// A plugin that adds [`Splitter`]s.
+pub struct SplitterPlugin;
+
+impl Plugin for SplitterPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<SplitterStyle>()
+            .add_observer(on_handle_press)
+            .add_observer(on_handle_drag)
+            .add_systems(PostUpdate, splitter_system.before(AnimationSystems));
+    }
+}
+
+/// Returns `count` fractions summing to one, the missing ones are the average of the given ones.
+pub fn normalize_fractions(fractions: &[f32], count: usize) -> Vec<f32> {
+    let given = fractions
+        .iter()
+        .take(count)
+        .map(|fraction| {
+            if fraction.is_finite() {
+                fraction.max(0.0)
+            } else {
+                0.0
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let sum = given.iter().sum::<f32>();
+    let average = if given.is_empty() || sum <= 0.0 {
+        1.0
+    } else {
+        sum / given.len() as f32
+    };
+
+    let mut fractions = given;
+    fractions.resize(count, average);
+
+    let sum = fractions.iter().sum::<f32>();
+    if sum > 0.0 {
+        fractions.iter_mut().for_each(|fraction| *fraction /= sum);
+    } else {
+        fractions.fill(1.0 / count.max(1) as f32);
+    }
+
+    fractions
+}
+
+/// Sizes the panes by their fractions, and places a handle on each boundary.
+pub fn splitter_system(
+    splitter_style: Res<SplitterStyle>,
+    mut splitter_query: Query<(Entity, &mut Splitter, Option<&Children>)>,
+    pane_query: Query<(), With<SplitPane>>,
+    handle_query: Query<&SplitterHandle>,
+    mut style_query: Query<&mut Style>,
+    mut commands: Commands,
+) {
+    for (entity, mut splitter, children) in splitter_query.iter_mut() {
+        let children = children.map(|children| &children[..]).unwrap_or_default();
+        let panes = children
+            .iter()
+            .copied()
+            .filter(|&child| pane_query.contains(child))
+            .collect::<Vec<_>>();
+
+        let fractions = normalize_fractions(&splitter.fractions, panes.len());
+        if splitter.fractions != fractions {
+            splitter.fractions = fractions;
+        }
+
+        let direction = match splitter.direction {
+            SplitDirection::Horizontal => FlexDirection::Row,
+            SplitDirection::Vertical => FlexDirection::Column,
+        };
+        if let Ok(mut style) = style_query.get_mut(entity)
+            && style.get_ref().flex_direction != direction
+        {
+            style.get_mut().flex_direction = direction;
+        }
+
+        for (&pane, &fraction) in panes.iter().zip(&splitter.fractions) {
+            let Ok(mut style) = style_query.get_mut(pane) else {
+                continue;
+            };
+
+            let basis = Dimension::percent(fraction);
+            let inner = style.get_ref();
+            if inner.flex_basis != basis || inner.flex_grow != 0.0 || inner.flex_shrink != 1.0 {
+                let inner = style.get_mut();
+                inner.flex_basis = basis;
+                inner.flex_grow = 0.0;
+                inner.flex_shrink = 1.0;
+            }
+        }
+
+        let cursor = HoverCursor::from(match splitter.direction {
+            SplitDirection::Horizontal => SystemCursorIcon::ColResize,
+            SplitDirection::Vertical => SystemCursorIcon::RowResize,
+        });
+
+        let mut handles = children
+            .iter()
+            .copied()
+            .filter(|&child| handle_query.contains(child))
+            .collect::<Vec<_>>();
+
+        let count = panes.len().saturating_sub(1);
+        for handle in handles.drain(count.min(handles.len())..) {
+            commands.entity(handle).try_despawn();
+        }
+
+        let half = 0.5 * splitter.handle_size;
+        let mut boundary = 0.0;
+
+        for (index, &fraction) in splitter.fractions.iter().take(count).enumerate() {
+            boundary += fraction;
+
+            let along = LengthPercentageAuto::percent(boundary);
+            let zero = LengthPercentageAuto::length(0.0);
+            let node = match splitter.direction {
+                SplitDirection::Horizontal => taffy::Style {
+                    position: Position::Absolute,
+                    size: taffy::Size {
+                        width: Dimension::length(splitter.handle_size),
+                        height: Dimension::auto(),
+                    },
+                    inset: taffy::Rect {
+                        left: along,
+                        right: LengthPercentageAuto::auto(),
+                        top: zero,
+                        bottom: zero,
+                    },
+                    margin: taffy::Rect {
+                        left: LengthPercentageAuto::length(-half),
+                        right: zero,
+                        top: zero,
+                        bottom: zero,
+                    },
+                    ..Default::default()
+                },
+                SplitDirection::Vertical => taffy::Style {
+                    position: Position::Absolute,
+                    size: taffy::Size {
+                        width: Dimension::auto(),
+                        height: Dimension::length(splitter.handle_size),
+                    },
+                    inset: taffy::Rect {
+                        left: zero,
+                        right: zero,
+                        top: along,
+                        bottom: LengthPercentageAuto::auto(),
+                    },
+                    margin: taffy::Rect {
+                        left: zero,
+                        right: zero,
+                        top: LengthPercentageAuto::length(-half),
+                        bottom: zero,
+                    },
+                    ..Default::default()
+                },
+            };
+
+            let Some(&handle) = handles.get(index) else {
+                commands.spawn((
+                    SplitterHandle {
+                        splitter: entity,
+                        index,
+                        camera: None,
+                    },
+                    ChildOf(entity),
+                    ZIndex(1),
+                    cursor.clone(),
+                    Style::from(node).background(splitter_style.handle_color),
+                    StyleStates::new()
+                        .hovered(StyleVariant::new().background(splitter_style.hovered_color))
+                        .pressed(StyleVariant::new().background(splitter_style.hovered_color)),
+                ));
+                continue;
+            };
+
+            let Ok(mut style) = style_query.get_mut(handle) else {
+                continue;
+            };
+
+            let inner = style.get_ref();
+            if inner.size != node.size || inner.inset != node.inset || inner.margin != node.margin {
+                let inner = style.get_mut();
+                inner.size = node.size;
+                inner.inset = node.inset;
+                inner.margin = node.margin;
+                commands.entity(handle).try_insert(cursor.clone());
+            }
+        }
+    }
+}
+
+fn on_handle_press(mut event: On<Pointer<Press>>, mut handle_query: Query<&mut SplitterHandle>) {
+    let Ok(mut handle) = handle_query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    handle.camera = Some(event.hit.camera);
+}
+
+fn on_handle_drag(
+    mut event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    handle_query: Query<&SplitterHandle>,
+    mut splitter_query: Query<(
+        &mut Splitter,
+        &GlobalTransform,
+        &ComputedNode,
+        Option<&Interaction>,
+    )>,
+    mut commands: Commands,
+) {
+    let Ok(handle) = handle_query.get(event.entity) else {
+        return;
+    };
+    let Some(camera) = handle.camera else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    let Ok((mut splitter, transform, computed_node, interaction)) =
+        splitter_query.get_mut(handle.splitter)
+    else {
+        return;
+    };
+    if interaction.is_some_and(|interaction| interaction.disabled) {
+        return;
+    }
+
+    let index = handle.index;
+    if index + 1 >= splitter.fractions.len() {
+        return;
+    }
+
+    let Some(local_point) =
+        pointer_local_point(&cameras, camera, event.pointer_location.position, transform)
+    else {
+        return;
+    };
+
+    // The fraction along the content box, from its start.
+    let [left, _, _, top] = computed_node.border;
+    let [padding_left, _, _, padding_top] = computed_node.padding;
+    let half_size = 0.5 * computed_node.size;
+    let (offset, length) = match splitter.direction {
+        SplitDirection::Horizontal => (
+            local_point.x + half_size.x - left - padding_left,
+            computed_node.content_box_size.x,
+        ),
+        SplitDirection::Vertical => (
+            half_size.y - local_point.y - top - padding_top,
+            computed_node.content_box_size.y,
+        ),
+    };
+    if length <= 0.0 {
+        return;
+    }
+
+    let before = splitter.fractions[..index].iter().sum::<f32>();
+    let pair = splitter.fractions[index] + splitter.fractions[index + 1];
+    let min = (splitter.min_pane_size / length).min(0.5 * pair);
+
+    let boundary = (offset / length).clamp(before + min, before + pair - min);
+    let fraction = boundary - before;
+    if fraction == splitter.fractions[index] {
+        return;
+    }
+
+    splitter.fractions[index] = fraction;
+    splitter.fractions[index + 1] = pair - fraction;
+
+    commands.trigger(ValueChange {
+        entity: handle.splitter,
+        value: splitter.fractions.clone(),
+    });
+}
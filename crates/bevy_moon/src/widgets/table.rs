@@ -0,0 +1,950 @@
+//! Tables with sortable and resizable columns.
+//!
+//! A [`Table`] builds a header row and a [`VirtualList`] body, both laid out as grids with the
+//! widths of the columns. The header stays in place while the body is scrolled.
+
+use core::cmp::Ordering;
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_color::Color;
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    lifecycle::Add,
+    observer::On,
+    query::{Changed, Has, With},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut, SystemParam},
+};
+use bevy_input::{ButtonInput, keyboard::KeyCode};
+use bevy_input_focus::{InputFocus, tab_navigation::TabIndex};
+use bevy_picking::{
+    Pickable,
+    events::{Click, Drag, Pointer, Press},
+    pointer::PointerButton,
+};
+use bevy_text::{LineBreak, TextColor, TextFont, TextLayout};
+use bevy_transform::components::GlobalTransform;
+use bevy_window::SystemCursorIcon;
+use taffy::{
+    AlignItems, Dimension, Display, FlexDirection, GridTemplateComponent, LengthPercentage,
+    LengthPercentageAuto, Overflow, Position, style_helpers::length,
+};
+
+use crate::{
+    computed::ComputedNode,
+    cursor::HoverCursor,
+    elements::{node::Node, text::Text},
+    interaction::{Checked, Interaction},
+    picking::pointer_local_point,
+    states::{StyleStates, StyleVariant},
+    style::Style,
+    virtualize::{VirtualItem, VirtualItemBind, VirtualList, virtual_list_system},
+};
+
+use super::ValueChange;
+
+/// A column of a [`Table`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct TableColumn {
+    pub title: String,
+    pub width: f32,
+    pub min_width: f32,
+    /// Sorts the rows by the column when its header is clicked.
+    pub sortable: bool,
+    /// Resizes the column by dragging the right edge of its header.
+    pub resizable: bool,
+}
+
+impl TableColumn {
+    #[inline]
+    pub fn new(title: impl Into<String>, width: f32) -> Self {
+        Self {
+            title: title.into(),
+            width,
+            min_width: 32.0,
+            sortable: true,
+            resizable: true,
+        }
+    }
+
+    #[inline]
+    pub const fn min_width(mut self, width: f32) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    #[inline]
+    pub const fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    #[inline]
+    pub const fn resizable(mut self, resizable: bool) -> Self {
+        self.resizable = resizable;
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SortDirection {
+    #[default]
+    Ascending,
+    Descending,
+}
+
+/// The column which the rows of a [`Table`] are sorted by.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TableSort {
+    pub column: usize,
+    pub direction: SortDirection,
+}
+
+/// A table of text cells, the rows are shown in the order of the sort.
+///
+/// Clicking a sortable header sorts the rows ascending, descending, then unsorted,
+/// triggers a [`ValueChange<Option<TableSort>>`]. Dragging the right edge of a header
+/// resizes the column, triggers a [`ColumnResize`]. Clicking a row selects it, with `Shift`
+/// for a range and `Ctrl` or `Cmd` to toggle it, triggers a [`ValueChange<Vec<usize>>`]
+/// with the selected indices of [`Table::rows`].
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(
+    Node,
+    Interaction,
+    TabIndex,
+    AccessibilityNode = AccessibilityNode::from(accesskit::Node::new(Role::Table))
+)]
+pub struct Table {
+    pub columns: Vec<TableColumn>,
+    /// The rows are sorted again when their number or the sort changes, replace them with
+    /// [`Table::set_rows`] after editing their cells.
+    pub rows: Vec<Vec<String>>,
+    pub sort: Option<TableSort>,
+    /// Allows the range and toggle selections.
+    pub multi_select: bool,
+    selection: Vec<usize>,
+    anchor: Option<usize>,
+    /// The indices of the rows in the shown order.
+    order: Vec<usize>,
+    /// The sort and the number of rows of the order, `None` if the rows must be sorted again.
+    sorted: Option<(Option<TableSort>, usize)>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl Table {
+    #[inline]
+    pub const fn new(columns: Vec<TableColumn>) -> Self {
+        Self {
+            columns,
+            rows: Vec::new(),
+            sort: None,
+            multi_select: true,
+            selection: Vec::new(),
+            anchor: None,
+            order: Vec::new(),
+            sorted: None,
+        }
+    }
+
+    #[inline]
+    pub fn rows(mut self, rows: Vec<Vec<String>>) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// Replaces the rows, and sorts them again.
+    #[inline]
+    pub fn set_rows(&mut self, rows: Vec<Vec<String>>) {
+        self.rows = rows;
+        self.sorted = None;
+    }
+
+    #[inline]
+    pub const fn sort(mut self, sort: TableSort) -> Self {
+        self.sort = Some(sort);
+        self
+    }
+
+    #[inline]
+    pub const fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Returns the selected row indices, in the order they were selected.
+    #[inline]
+    pub fn selection(&self) -> &[usize] {
+        &self.selection
+    }
+
+    #[inline]
+    pub fn is_selected(&self, row: usize) -> bool {
+        self.selection.contains(&row)
+    }
+
+    /// Returns the indices of the rows in the shown order.
+    #[inline]
+    pub fn order(&self) -> &[usize] {
+        &self.order
+    }
+
+    /// Selects a row, with the range and toggle modes of the tree views.
+    pub fn select(&mut self, row: usize, range: bool, toggle: bool) {
+        if row >= self.rows.len() {
+            return;
+        }
+
+        if self.multi_select && range {
+            let position = |row: usize| self.order.iter().position(|index| *index == row);
+            if let Some((anchor, index)) = self.anchor.and_then(position).zip(position(row)) {
+                self.selection = self.order[anchor.min(index)..=anchor.max(index)].to_vec();
+                return;
+            }
+        }
+
+        if self.multi_select && toggle {
+            if let Some(index) = self.selection.iter().position(|index| *index == row) {
+                self.selection.remove(index);
+            } else {
+                self.selection.push(row);
+            }
+        } else {
+            self.selection.clear();
+            self.selection.push(row);
+        }
+        self.anchor = Some(row);
+    }
+
+    #[inline]
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.anchor = None;
+    }
+
+    /// Sorts the shown order of the rows, if the rows or the sort changed.
+    fn sort_rows(&mut self) {
+        let sorted = Some((self.sort, self.rows.len()));
+        if self.sorted == sorted {
+            return;
+        }
+        self.sorted = sorted;
+
+        self.order.clear();
+        self.order.extend(0..self.rows.len());
+        self.selection.retain(|row| *row < self.rows.len());
+
+        let Some(sort) = self.sort.filter(|sort| sort.column < self.columns.len()) else {
+            return;
+        };
+
+        // The cells are parsed once, not on every comparison.
+        let keys = self
+            .rows
+            .iter()
+            .map(|row| {
+                let cell = row.get(sort.column).map(String::as_str).unwrap_or_default();
+                cell.trim().parse::<f64>().map_err(|_| cell)
+            })
+            .collect::<Vec<_>>();
+        self.order.sort_by(|a, b| {
+            let ordering = compare_cells(&keys[*a], &keys[*b]);
+            match sort.direction {
+                SortDirection::Ascending => ordering,
+                SortDirection::Descending => ordering.reverse(),
+            }
+        });
+    }
+
+    fn template_columns(&self) -> Vec<GridTemplateComponent<String>> {
+        self.columns
+            .iter()
+            .map(|column| length(column.width.max(column.min_width)))
+            .collect()
+    }
+}
+
+/// Compares two cells as numbers if both are numbers, otherwise as text.
+/// The numbers are ordered before the texts.
+fn compare_cells(a: &Result<f64, &str>, b: &Result<f64, &str>) -> Ordering {
+    match (a, b) {
+        (Ok(a), Ok(b)) => a.total_cmp(b),
+        (Ok(_), Err(_)) => Ordering::Less,
+        (Err(_), Ok(_)) => Ordering::Greater,
+        (Err(a), Err(b)) => a.cmp(b),
+    }
+}
+
+/// Triggered on a [`Table`] when a column is resized by the user.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct ColumnResize {
+    pub entity: Entity,
+    pub column: usize,
+    pub width: f32,
+}
+
+/// The child entities of a [`Table`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableParts {
+    pub header: Entity,
+    pub body: Entity,
+}
+
+/// The scrolled body of a [`Table`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TableBody {
+    pub table: Entity,
+}
+
+/// A header cell of a [`Table`].
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Interaction)]
+pub struct TableHeaderCell {
+    pub table: Entity,
+    pub column: usize,
+    title: Entity,
+    indicator: Entity,
+    /// Whether the cell has a [`ColumnResizeHandle`].
+    resizable: bool,
+}
+
+/// The grab region on the right edge of a [`TableHeaderCell`].
+#[derive(Component, Clone, Copy, Debug)]
+#[require(Interaction)]
+pub struct ColumnResizeHandle {
+    pub cell: Entity,
+    /// The camera of the current drag.
+    camera: Option<Entity>,
+}
+
+/// A row of a [`Table`], a recycled [`VirtualItem`].
+#[derive(Component, Clone, Debug)]
+pub struct TableRow {
+    pub table: Entity,
+    /// The cells and their texts.
+    cells: Vec<(Entity, Entity)>,
+}
+
+/// The look of the tables.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TableStyle {
+    pub row_height: f32,
+    /// The horizontal padding of the cells.
+    pub cell_padding: f32,
+    pub header_background: Color,
+    pub hovered_background: Color,
+    pub selected_background: Color,
+    pub text_color: Color,
+    pub header_text_color: Color,
+    pub handle_width: f32,
+    pub handle_color: Color,
+    pub font_size: f32,
+}
+
+impl Default for TableStyle {
+    fn default() -> Self {
+        Self {
+            row_height: 24.0,
+            cell_padding: 8.0,
+            header_background: Color::srgb(0.15, 0.15, 0.17),
+            hovered_background: Color::srgb(0.19, 0.19, 0.22),
+            selected_background: Color::srgb(0.24, 0.42, 0.8),
+            text_color: Color::srgb(0.92, 0.92, 0.92),
+            header_text_color: Color::srgb(0.75, 0.75, 0.78),
+            handle_width: 6.0,
+            handle_color: Color::srgba(0.3, 0.6, 1.0, 0.6),
+            font_size: 13.0,
+        }
+    }
+}
+
+/// A plugin that adds [`Table`]s.
+pub struct TablePlugin;
+
+impl Plugin for TablePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TableStyle>()
+            .add_observer(on_add_table)
+            .add_observer(on_table_row_bind)
+            .add_observer(on_header_click)
+            .add_observer(on_row_click)
+            .add_observer(on_resize_press)
+            .add_observer(on_resize_drag)
+            .add_systems(
+                PostUpdate,
+                table_system
+                    .before(virtual_list_system)
+                    .before(AnimationSystems),
+            );
+    }
+}
+
+/// A cell with a single line of text, clipped at the edge of its column.
+fn cell_style(style: &TableStyle) -> Style {
+    Style::from(taffy::Style {
+        flex_direction: FlexDirection::Row,
+        align_items: Some(AlignItems::Center),
+        min_size: taffy::Size {
+            width: Dimension::length(0.0),
+            height: Dimension::auto(),
+        },
+        overflow: taffy::Point {
+            x: Overflow::Clip,
+            y: Overflow::Clip,
+        },
+        padding: taffy::Rect {
+            left: LengthPercentage::length(style.cell_padding),
+            right: LengthPercentage::length(style.cell_padding),
+            top: LengthPercentage::length(0.0),
+            bottom: LengthPercentage::length(0.0),
+        },
+        ..Default::default()
+    })
+}
+
+fn cell_text(style: &TableStyle, text: &str, color: Color) -> impl bevy_ecs::bundle::Bundle {
+    (
+        Text::new(text),
+        TextFont::from_font_size(style.font_size),
+        TextColor(color),
+        TextLayout::new_with_linebreak(LineBreak::NoWrap),
+        Pickable::IGNORE,
+    )
+}
+
+fn on_add_table(
+    event: On<Add, Table>,
+    style: Res<TableStyle>,
+    mut table_query: Query<&mut Style>,
+    mut commands: Commands,
+) {
+    let table = event.entity;
+    let Ok(mut table_style) = table_query.get_mut(table) else {
+        return;
+    };
+
+    let inner = table_style.get_mut();
+    inner.flex_direction = FlexDirection::Column;
+    inner.overflow.x = Overflow::Clip;
+
+    let header = commands
+        .spawn((
+            Node,
+            ChildOf(table),
+            AccessibilityNode::from(accesskit::Node::new(Role::Row)),
+            Style::from(taffy::Style {
+                display: Display::Grid,
+                flex_shrink: 0.0,
+                size: taffy::Size {
+                    width: Dimension::auto(),
+                    height: Dimension::length(style.row_height),
+                },
+                ..Default::default()
+            })
+            .background(style.header_background),
+        ))
+        .id();
+
+    let body = commands
+        .spawn((
+            TableBody { table },
+            VirtualList::fixed(0, style.row_height),
+            ChildOf(table),
+            Style::from(taffy::Style {
+                flex_grow: 1.0,
+                min_size: taffy::Size {
+                    width: Dimension::auto(),
+                    height: Dimension::length(0.0),
+                },
+                overflow: taffy::Point {
+                    x: Overflow::Clip,
+                    y: Overflow::Scroll,
+                },
+                ..Default::default()
+            }),
+        ))
+        .id();
+
+    commands.entity(table).insert(TableParts { header, body });
+}
+
+/// Returns the sort indicator of a column.
+fn sort_indicator(table: &Table, column: usize) -> &'static str {
+    match table.sort {
+        Some(sort) if sort.column == column => match sort.direction {
+            SortDirection::Ascending => "▲",
+            SortDirection::Descending => "▼",
+        },
+        _ => "",
+    }
+}
+
+fn spawn_header_cell(
+    commands: &mut Commands,
+    style: &TableStyle,
+    table_entity: Entity,
+    table: &Table,
+    header: Entity,
+    column: usize,
+) -> Entity {
+    let title_text = table.columns[column].title.as_str();
+
+    let mut accessible = accesskit::Node::new(Role::ColumnHeader);
+    accessible.set_label(title_text);
+
+    let cell = commands
+        .spawn((
+            Node,
+            ChildOf(header),
+            cell_style(style),
+            StyleStates::new().hovered(StyleVariant::new().background(style.hovered_background)),
+            AccessibilityNode::from(accessible),
+        ))
+        .id();
+
+    let title = commands
+        .spawn((
+            cell_text(style, title_text, style.header_text_color),
+            ChildOf(cell),
+        ))
+        .id();
+    let indicator = commands
+        .spawn((
+            cell_text(
+                style,
+                sort_indicator(table, column),
+                style.header_text_color,
+            ),
+            ChildOf(cell),
+        ))
+        .id();
+
+    let resizable = table.columns[column].resizable;
+    if resizable {
+        commands.spawn((
+            ColumnResizeHandle { cell, camera: None },
+            ChildOf(cell),
+            HoverCursor::from(SystemCursorIcon::ColResize),
+            Style::from(taffy::Style {
+                position: Position::Absolute,
+                size: taffy::Size {
+                    width: Dimension::length(style.handle_width),
+                    height: Dimension::auto(),
+                },
+                inset: taffy::Rect {
+                    left: LengthPercentageAuto::auto(),
+                    right: LengthPercentageAuto::length(0.0),
+                    top: LengthPercentageAuto::length(0.0),
+                    bottom: LengthPercentageAuto::length(0.0),
+                },
+                ..Default::default()
+            }),
+            StyleStates::new()
+                .hovered(StyleVariant::new().background(style.handle_color))
+                .pressed(StyleVariant::new().background(style.handle_color)),
+        ));
+    }
+
+    commands.entity(cell).insert(TableHeaderCell {
+        table: table_entity,
+        column,
+        title,
+        indicator,
+        resizable,
+    });
+
+    cell
+}
+
+/// The header cells of the tables.
+#[derive(SystemParam)]
+pub struct TableHeaders<'w, 's> {
+    header_query: Query<'w, 's, &'static Children>,
+    cell_query: Query<
+        'w,
+        's,
+        (
+            &'static TableHeaderCell,
+            Option<&'static mut AccessibilityNode>,
+        ),
+    >,
+    text_query: Query<'w, 's, &'static mut Text>,
+}
+
+impl TableHeaders<'_, '_> {
+    /// Updates the titles and the sort indicators of the header cells of the table.
+    ///
+    /// The cells are rebuilt only when the number of columns or their resizability changes,
+    /// to not break a resize.
+    pub fn update(
+        &mut self,
+        commands: &mut Commands,
+        style: &TableStyle,
+        table_entity: Entity,
+        table: &Table,
+        header: Entity,
+    ) {
+        let cells = self
+            .header_query
+            .get(header)
+            .map(|children| {
+                children
+                    .iter()
+                    .copied()
+                    .filter(|&child| self.cell_query.contains(child))
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        let rebuild = cells.len() != table.columns.len()
+            || self.cell_query.iter_many(&cells).any(|(cell, _)| {
+                table
+                    .columns
+                    .get(cell.column)
+                    .is_none_or(|column| column.resizable != cell.resizable)
+            });
+        if rebuild {
+            for cell in cells {
+                commands.entity(cell).try_despawn();
+            }
+            for column in 0..table.columns.len() {
+                spawn_header_cell(commands, style, table_entity, table, header, column);
+            }
+            return;
+        }
+
+        for cell in cells {
+            let Ok((cell, accessible)) = self.cell_query.get_mut(cell) else {
+                continue;
+            };
+            let Some(column) = table.columns.get(cell.column) else {
+                continue;
+            };
+
+            let indicator = sort_indicator(table, cell.column);
+            for (entity, value) in [
+                (cell.title, column.title.as_str()),
+                (cell.indicator, indicator),
+            ] {
+                if let Ok(mut text) = self.text_query.get_mut(entity)
+                    && text.0 != value
+                {
+                    text.0 = value.to_owned();
+                }
+            }
+
+            if let Some(mut accessible) = accessible {
+                accessible.set_label(column.title.as_str());
+            }
+        }
+    }
+}
+
+/// Sorts the rows of the changed tables, updates their headers and rebinds their rows.
+pub fn table_system(
+    style: Res<TableStyle>,
+    mut table_query: Query<(Entity, &mut Table, &TableParts), Changed<Table>>,
+    mut headers: TableHeaders,
+    mut style_query: Query<&mut Style>,
+    mut list_query: Query<&mut VirtualList>,
+    mut commands: Commands,
+) {
+    for (entity, mut table, parts) in table_query.iter_mut() {
+        // The order is derived from the rows, it doesn't change the table.
+        table.bypass_change_detection().sort_rows();
+
+        let templates = table.template_columns();
+        if let Ok(mut header_style) = style_query.get_mut(parts.header)
+            && header_style.get_ref().grid_template_columns != templates
+        {
+            header_style.get_mut().grid_template_columns = templates;
+        }
+
+        headers.update(&mut commands, &style, entity, &table, parts.header);
+
+        // Binds the shown rows again, even if the number of rows is the same.
+        if let Ok(mut list) = list_query.get_mut(parts.body) {
+            list.len = table.rows.len();
+        }
+    }
+}
+
+fn on_table_row_bind(
+    event: On<VirtualItemBind>,
+    style: Res<TableStyle>,
+    body_query: Query<&TableBody>,
+    table_query: Query<&Table>,
+    mut row_query: Query<(Option<&mut TableRow>, Has<Checked>, &mut Style)>,
+    mut text_query: Query<&mut Text>,
+    mut commands: Commands,
+) {
+    let Ok(body) = body_query.get(event.list) else {
+        return;
+    };
+    let Ok(table) = table_query.get(body.table) else {
+        return;
+    };
+    let Some(cells) = table
+        .order()
+        .get(event.index)
+        .and_then(|&row| table.rows.get(row))
+    else {
+        return;
+    };
+    let Ok((table_row, checked, mut row_style)) = row_query.get_mut(event.entity) else {
+        return;
+    };
+
+    let templates = table.template_columns();
+    let inner = row_style.get_ref();
+    if inner.display != Display::Grid || inner.grid_template_columns != templates {
+        let inner = row_style.get_mut();
+        inner.display = Display::Grid;
+        inner.grid_template_columns = templates;
+    }
+
+    let cell_value = |column: usize| cells.get(column).map(String::as_str).unwrap_or_default();
+
+    match table_row {
+        Some(table_row) if table_row.cells.len() == table.columns.len() => {
+            for (column, &(_, text)) in table_row.cells.iter().enumerate() {
+                if let Ok(mut text) = text_query.get_mut(text)
+                    && text.0 != cell_value(column)
+                {
+                    text.0 = cell_value(column).to_owned();
+                }
+            }
+        }
+        table_row => {
+            let old = table_row
+                .map(|table_row| core::mem::take(&mut table_row.into_inner().cells))
+                .unwrap_or_default();
+            for (cell, _) in old {
+                commands.entity(cell).try_despawn();
+            }
+
+            let cells = (0..table.columns.len())
+                .map(|column| {
+                    let cell = commands
+                        .spawn((
+                            Node,
+                            ChildOf(event.entity),
+                            cell_style(&style),
+                            Pickable::IGNORE,
+                        ))
+                        .id();
+                    let text = commands
+                        .spawn((
+                            cell_text(&style, cell_value(column), style.text_color),
+                            ChildOf(cell),
+                        ))
+                        .id();
+                    (cell, text)
+                })
+                .collect();
+
+            commands.entity(event.entity).insert((
+                TableRow {
+                    table: body.table,
+                    cells,
+                },
+                AccessibilityNode::from(accesskit::Node::new(Role::Row)),
+                StyleStates::new()
+                    .hovered(StyleVariant::new().background(style.hovered_background))
+                    .checked(StyleVariant::new().background(style.selected_background)),
+            ));
+        }
+    }
+
+    let selected = table.is_selected(table.order()[event.index]);
+    if selected && !checked {
+        commands.entity(event.entity).insert(Checked);
+    } else if !selected && checked {
+        commands.entity(event.entity).remove::<Checked>();
+    }
+}
+
+fn on_header_click(
+    mut event: On<Pointer<Click>>,
+    cell_query: Query<&TableHeaderCell>,
+    handle_query: Query<(), With<ColumnResizeHandle>>,
+    mut table_query: Query<(&mut Table, &Interaction)>,
+    mut commands: Commands,
+) {
+    let Ok(cell) = cell_query.get(event.entity) else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    // The end of a resize isn't a sort.
+    if handle_query.contains(event.original_event_target()) {
+        return;
+    }
+
+    let Ok((mut table, interaction)) = table_query.get_mut(cell.table) else {
+        return;
+    };
+    if interaction.disabled
+        || !table
+            .columns
+            .get(cell.column)
+            .is_some_and(|column| column.sortable)
+    {
+        return;
+    }
+
+    // Ascending, descending, then unsorted.
+    table.sort = match table.sort {
+        Some(sort) if sort.column == cell.column => match sort.direction {
+            SortDirection::Ascending => Some(TableSort {
+                column: cell.column,
+                direction: SortDirection::Descending,
+            }),
+            SortDirection::Descending => None,
+        },
+        _ => Some(TableSort {
+            column: cell.column,
+            direction: SortDirection::Ascending,
+        }),
+    };
+
+    commands.trigger(ValueChange {
+        entity: cell.table,
+        value: table.sort,
+    });
+}
+
+fn on_row_click(
+    mut event: On<Pointer<Click>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    row_query: Query<(&TableRow, &VirtualItem)>,
+    mut table_query: Query<(&mut Table, &Interaction)>,
+    mut input_focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    let Ok((row, item)) = row_query.get(event.entity) else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    let Ok((mut table, interaction)) = table_query.get_mut(row.table) else {
+        return;
+    };
+    let Some(&index) = table.order().get(item.index) else {
+        return;
+    };
+    if interaction.disabled {
+        return;
+    }
+
+    input_focus.set(row.table);
+
+    let range = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let toggle = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    let before = table.selection().to_vec();
+    table.select(index, range, toggle);
+
+    if table.selection() != before {
+        commands.trigger(ValueChange {
+            entity: row.table,
+            value: table.selection().to_vec(),
+        });
+    }
+}
+
+fn on_resize_press(
+    mut event: On<Pointer<Press>>,
+    mut handle_query: Query<&mut ColumnResizeHandle>,
+) {
+    let Ok(mut handle) = handle_query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    handle.camera = Some(event.hit.camera);
+}
+
+/// Resizes the column to the pointer, from the left edge of its header.
+fn on_resize_drag(
+    mut event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    handle_query: Query<&ColumnResizeHandle>,
+    cell_query: Query<(&TableHeaderCell, &GlobalTransform, &ComputedNode)>,
+    mut table_query: Query<(&mut Table, &Interaction)>,
+    mut commands: Commands,
+) {
+    let Ok(handle) = handle_query.get(event.entity) else {
+        return;
+    };
+    let Some(camera) = handle.camera else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    let Ok((cell, transform, computed_node)) = cell_query.get(handle.cell) else {
+        return;
+    };
+    let Ok((mut table, interaction)) = table_query.get_mut(cell.table) else {
+        return;
+    };
+    let Some(column) = table
+        .columns
+        .get(cell.column)
+        .filter(|column| column.resizable && !interaction.disabled)
+    else {
+        return;
+    };
+    let Some(local_point) =
+        pointer_local_point(&cameras, camera, event.pointer_location.position, transform)
+    else {
+        return;
+    };
+
+    let width = (local_point.x + 0.5 * computed_node.size.x).max(column.min_width);
+    if width == column.width {
+        return;
+    }
+
+    table.columns[cell.column].width = width;
+
+    commands.trigger(ColumnResize {
+        entity: cell.table,
+        column: cell.column,
+        width,
+    });
+}
@@ -0,0 +1,689 @@
+//! Tab views with closable and reorderable tabs.
+//!
+//! A [`TabView`] builds its tab bar as the first child, the [`TabPanel`] children are shown
+//! while their tab is selected. The order of the tabs and the selected one are saved with a [`TabViewLayout`].
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    lifecycle::Add,
+    observer::On,
+    query::{Changed, Has},
+    reflect::ReflectComponent,
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res},
+};
+use bevy_math::Vec2;
+use bevy_picking::{
+    Pickable,
+    events::{Drag, Pointer, Press},
+    pointer::PointerButton,
+};
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+#[cfg(feature = "serialize")]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+use bevy_text::{TextColor, TextFont};
+use bevy_transform::components::GlobalTransform;
+use taffy::{AlignItems, Display, FlexDirection, LengthPercentage};
+
+use crate::{
+    elements::{node::Node, text::Text},
+    interaction::Checked,
+    picking::pointer_local_point,
+    states::{StyleStates, StyleVariant},
+    style::{Corners, Style},
+};
+
+use super::{Activatable, Activate, ValueChange, accessible_node};
+
+/// A tab of a [`TabView`], identified by its key.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+pub struct TabItem {
+    /// Matches the key of the [`TabPanel`] of the tab.
+    pub key: String,
+    pub label: String,
+    /// Shows a close button on the tab.
+    pub closable: bool,
+}
+
+impl TabItem {
+    #[inline]
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            closable: false,
+        }
+    }
+
+    #[inline]
+    pub const fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+}
+
+/// A tab view, a bar of tabs above the [`TabPanel`]s of its children.
+///
+/// Activating a tab selects it and triggers a [`ValueChange<String>`] with its key.
+/// Closing a tab removes it and triggers a [`TabClose`], dragging a tab along the bar triggers a [`TabMove`].
+#[derive(Component, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Node)]
+pub struct TabView {
+    pub tabs: Vec<TabItem>,
+    /// The key of the selected tab, the first tab is selected if it's not found.
+    pub selected: Option<String>,
+    /// Reorders the tabs by dragging them.
+    pub reorderable: bool,
+}
+
+impl Default for TabView {
+    fn default() -> Self {
+        Self::new(Vec::new())
+    }
+}
+
+impl TabView {
+    #[inline]
+    pub const fn new(tabs: Vec<TabItem>) -> Self {
+        Self {
+            tabs,
+            selected: None,
+            reorderable: true,
+        }
+    }
+
+    #[inline]
+    pub fn selected(mut self, key: impl Into<String>) -> Self {
+        self.selected = Some(key.into());
+        self
+    }
+
+    #[inline]
+    pub const fn reorderable(mut self, reorderable: bool) -> Self {
+        self.reorderable = reorderable;
+        self
+    }
+
+    /// Returns the index of the tab with the key.
+    #[inline]
+    pub fn position(&self, key: &str) -> Option<usize> {
+        self.tabs.iter().position(|tab| tab.key == key)
+    }
+
+    /// Returns the key of the shown tab.
+    pub fn selected_key(&self) -> Option<&str> {
+        self.selected
+            .as_deref()
+            .filter(|key| self.position(key).is_some())
+            .or_else(|| self.tabs.first().map(|tab| tab.key.as_str()))
+    }
+
+    /// Returns the persistent state of the tabs.
+    pub fn layout(&self) -> TabViewLayout {
+        TabViewLayout {
+            tabs: self.tabs.iter().map(|tab| tab.key.clone()).collect(),
+            selected: self.selected_key().map(ToOwned::to_owned),
+        }
+    }
+
+    /// Restores the order of the tabs and the selected one.
+    ///
+    /// The tabs missing from the layout are removed, the unknown keys of the layout are skipped.
+    pub fn apply_layout(&mut self, layout: &TabViewLayout) {
+        let mut tabs = core::mem::take(&mut self.tabs);
+
+        self.tabs = layout
+            .tabs
+            .iter()
+            .filter_map(|key| {
+                let index = tabs.iter().position(|tab| tab.key == *key)?;
+                Some(tabs.swap_remove(index))
+            })
+            .collect();
+
+        if layout.selected.is_some() {
+            self.selected.clone_from(&layout.selected);
+        }
+    }
+}
+
+/// The persistent state of a [`TabView`], the keys of its tabs in order and the selected key.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Default, Debug, PartialEq, Clone)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    reflect(Serialize, Deserialize)
+)]
+pub struct TabViewLayout {
+    pub tabs: Vec<String>,
+    pub selected: Option<String>,
+}
+
+/// The content of a tab, a child of a [`TabView`] shown while the tab with the key is selected.
+///
+/// A hidden panel has a `display` of none, a shown panel without a `display` is set to flex.
+#[derive(Component, Clone, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(
+    Node,
+    AccessibilityNode = AccessibilityNode::from(accesskit::Node::new(Role::TabPanel))
+)]
+pub struct TabPanel {
+    pub key: String,
+}
+
+impl TabPanel {
+    #[inline]
+    pub fn new(key: impl Into<String>) -> Self {
+        Self { key: key.into() }
+    }
+}
+
+/// The tab bar of a [`TabView`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TabBar {
+    pub view: Entity,
+}
+
+/// A tab in a [`TabBar`].
+#[derive(Component, Clone, Debug)]
+#[require(Activatable, TabDrag)]
+pub struct TabHeader {
+    pub view: Entity,
+    pub key: String,
+    label: Entity,
+    closable: bool,
+}
+
+/// The close button of a [`TabHeader`].
+#[derive(Component, Clone, Debug)]
+#[require(Activatable)]
+pub struct TabCloseButton {
+    pub view: Entity,
+    pub key: String,
+}
+
+/// The pointer dragging a [`TabHeader`].
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq)]
+pub struct TabDrag {
+    /// The camera of the current drag.
+    camera: Option<Entity>,
+}
+
+/// The child entities of a [`TabView`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TabViewParts {
+    pub bar: Entity,
+}
+
+/// Triggered on a [`TabView`] when a tab is closed by the user.
+///
+/// The panel of the tab is hidden, despawn it here to free it.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TabClose {
+    pub entity: Entity,
+    pub key: String,
+}
+
+/// Triggered on a [`TabView`] when a tab is dragged to another position.
+#[derive(EntityEvent, Clone, Debug)]
+pub struct TabMove {
+    pub entity: Entity,
+    pub key: String,
+    pub from: usize,
+    pub to: usize,
+}
+
+/// The look of the tab views.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TabStyle {
+    pub bar_background: Color,
+    pub hovered_background: Color,
+    pub selected_background: Color,
+    pub text_color: Color,
+    pub close_color: Color,
+    pub font_size: f32,
+    /// The horizontal and vertical padding of the tabs.
+    pub padding: Vec2,
+    pub gap: f32,
+    pub corner_radius: f32,
+}
+
+impl Default for TabStyle {
+    fn default() -> Self {
+        Self {
+            bar_background: Color::srgb(0.12, 0.12, 0.14),
+            hovered_background: Color::srgb(0.19, 0.19, 0.22),
+            selected_background: Color::srgb(0.22, 0.22, 0.26),
+            text_color: Color::srgb(0.92, 0.92, 0.92),
+            close_color: Color::srgb(0.6, 0.6, 0.62),
+            font_size: 13.0,
+            padding: Vec2::new(12.0, 6.0),
+            gap: 2.0,
+            corner_radius: 4.0,
+        }
+    }
+}
+
+/// A plugin that adds [`TabView`]s.
+pub struct TabsPlugin;
+
+impl Plugin for TabsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TabStyle>()
+            .add_observer(on_add_tab_view)
+            .add_observer(on_tab_activate)
+            .add_observer(on_tab_close)
+            .add_observer(on_tab_press)
+            .add_observer(on_tab_drag)
+            .add_systems(
+                PostUpdate,
+                (tab_bar_system, tab_panel_system).before(AnimationSystems),
+            );
+    }
+}
+
+/// Selects a tab, triggers a [`ValueChange<String>`] if it's changed.
+fn select_tab(commands: &mut Commands, entity: Entity, view: &mut TabView, key: &str) {
+    if view.selected.as_deref() == Some(key) {
+        return;
+    }
+
+    let changed = view.selected_key() != Some(key);
+    view.selected = Some(key.to_owned());
+
+    if changed {
+        commands.trigger(ValueChange {
+            entity,
+            value: key.to_owned(),
+        });
+    }
+}
+
+fn on_add_tab_view(
+    event: On<Add, TabView>,
+    style: Res<TabStyle>,
+    mut view_query: Query<&mut Style>,
+    mut commands: Commands,
+) {
+    let view = event.entity;
+    let Ok(mut view_style) = view_query.get_mut(view) else {
+        return;
+    };
+
+    view_style.get_mut().flex_direction = FlexDirection::Column;
+
+    let bar = commands
+        .spawn((
+            Node,
+            TabBar { view },
+            AccessibilityNode::from(accesskit::Node::new(Role::TabList)),
+            Style::from(taffy::Style {
+                flex_direction: FlexDirection::Row,
+                align_items: Some(AlignItems::End),
+                flex_shrink: 0.0,
+                gap: taffy::Size {
+                    width: LengthPercentage::length(style.gap),
+                    height: LengthPercentage::length(0.0),
+                },
+                padding: taffy::Rect {
+                    left: LengthPercentage::length(style.gap),
+                    right: LengthPercentage::length(style.gap),
+                    top: LengthPercentage::length(style.gap),
+                    bottom: LengthPercentage::length(0.0),
+                },
+                ..Default::default()
+            })
+            .background(style.bar_background),
+        ))
+        .id();
+
+    commands
+        .entity(view)
+        .insert_children(0, &[bar])
+        .insert(TabViewParts { bar });
+}
+
+fn spawn_header(
+    commands: &mut Commands,
+    style: &TabStyle,
+    view: Entity,
+    tab: &TabItem,
+    selected: bool,
+) -> Entity {
+    let mut accessible = accessible_node(Role::Tab);
+    accessible.set_label(tab.label.as_str());
+    accessible.set_selected(selected);
+
+    let header = commands
+        .spawn((
+            Style::from(taffy::Style {
+                flex_direction: FlexDirection::Row,
+                align_items: Some(AlignItems::Center),
+                flex_shrink: 0.0,
+                gap: taffy::Size {
+                    width: LengthPercentage::length(0.5 * style.padding.x),
+                    height: LengthPercentage::length(0.0),
+                },
+                padding: taffy::Rect {
+                    left: LengthPercentage::length(style.padding.x),
+                    right: LengthPercentage::length(style.padding.x),
+                    top: LengthPercentage::length(style.padding.y),
+                    bottom: LengthPercentage::length(style.padding.y),
+                },
+                ..Default::default()
+            })
+            .corner_radii(Corners {
+                top_left: style.corner_radius,
+                top_right: style.corner_radius,
+                bottom_left: 0.0,
+                bottom_right: 0.0,
+            }),
+            StyleStates::new()
+                .hovered(StyleVariant::new().background(style.hovered_background))
+                .checked(StyleVariant::new().background(style.selected_background)),
+            accessible,
+        ))
+        .id();
+
+    let label = commands
+        .spawn((
+            Text::new(tab.label.as_str()),
+            TextFont::from_font_size(style.font_size),
+            TextColor(style.text_color),
+            Pickable::IGNORE,
+            ChildOf(header),
+        ))
+        .id();
+
+    if tab.closable {
+        let mut accessible = accessible_node(Role::Button);
+        accessible.set_label("Close");
+
+        commands
+            .spawn((
+                TabCloseButton {
+                    view,
+                    key: tab.key.clone(),
+                },
+                ChildOf(header),
+                accessible,
+                Style::from(taffy::Style {
+                    padding: taffy::Rect {
+                        left: LengthPercentage::length(3.0),
+                        right: LengthPercentage::length(3.0),
+                        top: LengthPercentage::length(0.0),
+                        bottom: LengthPercentage::length(0.0),
+                    },
+                    ..Default::default()
+                })
+                .corner_radii(Corners::all(style.corner_radius)),
+                StyleStates::new()
+                    .hovered(StyleVariant::new().background(style.hovered_background)),
+            ))
+            .with_child((
+                Text::new("×"),
+                TextFont::from_font_size(style.font_size),
+                TextColor(style.close_color),
+                Pickable::IGNORE,
+            ));
+    }
+
+    let mut entity = commands.entity(header);
+    entity.insert(TabHeader {
+        view,
+        key: tab.key.clone(),
+        label,
+        closable: tab.closable,
+    });
+    if selected {
+        entity.insert(Checked);
+    }
+
+    header
+}
+
+/// Builds the tabs of the changed tab views, keeps the existing tabs to not break a drag.
+pub fn tab_bar_system(
+    style: Res<TabStyle>,
+    view_query: Query<(Entity, &TabView, &TabViewParts), Changed<TabView>>,
+    bar_query: Query<&Children>,
+    mut header_query: Query<(&TabHeader, Has<Checked>, Option<&mut AccessibilityNode>)>,
+    mut text_query: Query<&mut Text>,
+    mut commands: Commands,
+) {
+    for (view_entity, view, parts) in view_query.iter() {
+        let children = bar_query
+            .get(parts.bar)
+            .map(|children| children.to_vec())
+            .unwrap_or_default();
+
+        let mut headers = Vec::with_capacity(view.tabs.len());
+        for tab in &view.tabs {
+            let selected = view.selected_key() == Some(tab.key.as_str());
+            let existing = children.iter().copied().find(|&child| {
+                header_query.get(child).is_ok_and(|(header, ..)| {
+                    header.key == tab.key && header.closable == tab.closable
+                })
+            });
+
+            let Some(entity) = existing else {
+                headers.push(spawn_header(
+                    &mut commands,
+                    &style,
+                    view_entity,
+                    tab,
+                    selected,
+                ));
+                continue;
+            };
+            headers.push(entity);
+
+            let Ok((header, checked, accessible)) = header_query.get_mut(entity) else {
+                continue;
+            };
+            if let Ok(mut text) = text_query.get_mut(header.label)
+                && text.0 != tab.label
+            {
+                text.0.clone_from(&tab.label);
+            }
+            if let Some(mut accessible) = accessible {
+                accessible.set_label(tab.label.as_str());
+                accessible.set_selected(selected);
+            }
+
+            if selected && !checked {
+                commands.entity(entity).try_insert(Checked);
+            } else if !selected && checked {
+                commands.entity(entity).try_remove::<Checked>();
+            }
+        }
+
+        for &child in &children {
+            if !headers.contains(&child) {
+                commands.entity(child).try_despawn();
+            }
+        }
+
+        if children != headers {
+            commands.entity(parts.bar).replace_children(&headers);
+        }
+    }
+}
+
+/// Shows the panels of the selected tabs.
+pub fn tab_panel_system(
+    view_query: Query<&TabView>,
+    mut panel_query: Query<(&TabPanel, &ChildOf, &mut Style)>,
+) {
+    for (panel, child_of, mut style) in panel_query.iter_mut() {
+        let Ok(view) = view_query.get(child_of.parent()) else {
+            continue;
+        };
+
+        let shown = view.selected_key() == Some(panel.key.as_str());
+        let display = style.get_ref().display;
+
+        if !shown && display != Display::None {
+            style.get_mut().display = Display::None;
+        } else if shown && display == Display::None {
+            style.get_mut().display = Display::Flex;
+        }
+    }
+}
+
+fn on_tab_activate(
+    event: On<Activate>,
+    header_query: Query<&TabHeader>,
+    mut view_query: Query<&mut TabView>,
+    mut commands: Commands,
+) {
+    let Ok(header) = header_query.get(event.entity) else {
+        return;
+    };
+    let Ok(mut view) = view_query.get_mut(header.view) else {
+        return;
+    };
+
+    select_tab(&mut commands, header.view, &mut view, &header.key);
+}
+
+fn on_tab_close(
+    event: On<Activate>,
+    button_query: Query<&TabCloseButton>,
+    mut view_query: Query<&mut TabView>,
+    mut commands: Commands,
+) {
+    let Ok(button) = button_query.get(event.entity) else {
+        return;
+    };
+    let Ok(mut view) = view_query.get_mut(button.view) else {
+        return;
+    };
+    let Some(index) = view.position(&button.key) else {
+        return;
+    };
+
+    let was_selected = view.selected_key() == Some(button.key.as_str());
+    view.tabs.remove(index);
+
+    commands.trigger(TabClose {
+        entity: button.view,
+        key: button.key.clone(),
+    });
+
+    // Selects the next tab, or the previous one for the last tab.
+    if was_selected {
+        let next = view
+            .tabs
+            .get(index.min(view.tabs.len().saturating_sub(1)))
+            .map(|tab| tab.key.clone());
+
+        view.selected.clone_from(&next);
+
+        if let Some(key) = next {
+            commands.trigger(ValueChange {
+                entity: button.view,
+                value: key,
+            });
+        }
+    }
+}
+
+fn on_tab_press(mut event: On<Pointer<Press>>, mut header_query: Query<&mut TabDrag>) {
+    let Ok(mut drag) = header_query.get_mut(event.entity) else {
+        return;
+    };
+
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    drag.camera = Some(event.hit.camera);
+}
+
+/// Moves the dragged tab past the centers of its neighbors.
+fn on_tab_drag(
+    mut event: On<Pointer<Drag>>,
+    cameras: Query<(&Camera, &GlobalTransform)>,
+    header_query: Query<(&TabHeader, &TabDrag)>,
+    transform_query: Query<&GlobalTransform>,
+    bar_query: Query<&Children>,
+    mut view_query: Query<(&mut TabView, &TabViewParts)>,
+    mut commands: Commands,
+) {
+    let Ok((header, drag)) = header_query.get(event.entity) else {
+        return;
+    };
+    let Some(camera) = drag.camera else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    let Ok((mut view, parts)) = view_query.get_mut(header.view) else {
+        return;
+    };
+    if !view.reorderable {
+        return;
+    }
+    let Some(from) = view.position(&header.key) else {
+        return;
+    };
+
+    let position = event.pointer_location.position;
+    let mut to = from;
+
+    for &child in bar_query.get(parts.bar).into_iter().flatten() {
+        let Ok((other, _)) = header_query.get(child) else {
+            continue;
+        };
+        let Some(index) = view.position(&other.key) else {
+            continue;
+        };
+        let Some(local_point) = transform_query
+            .get(child)
+            .ok()
+            .and_then(|transform| pointer_local_point(&cameras, camera, position, transform))
+        else {
+            continue;
+        };
+
+        if (index > from && local_point.x > 0.0 && index > to)
+            || (index < from && local_point.x < 0.0 && (to == from || index < to))
+        {
+            to = index;
+        }
+    }
+
+    if to == from {
+        return;
+    }
+
+    let tab = view.tabs.remove(from);
+    view.tabs.insert(to, tab);
+
+    commands.trigger(TabMove {
+        entity: header.view,
+        key: header.key.clone(),
+        from,
+        to,
+    });
+}
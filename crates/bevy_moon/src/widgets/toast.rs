@@ -0,0 +1,294 @@
+//! Toast notifications.
+//!
+//! [`Toast`]s are spawned as children of a [`ToastStack`], a [`ScreenSpace`] root anchored to a
+//! corner of the viewport of its camera. They are dismissed after their duration, or with a click.
+
+use core::time::Duration;
+
+use accesskit::{Live, Role};
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{App, Plugin, Update};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::{ChildOf, Children},
+    lifecycle::Add,
+    observer::On,
+    query::With,
+    resource::Resource,
+    system::{Commands, Query, Res},
+};
+use bevy_math::Vec2;
+use bevy_picking::{
+    Pickable,
+    events::{Click, Pointer},
+    pointer::PointerButton,
+};
+use bevy_text::{TextColor, TextFont};
+use bevy_time::Time;
+use taffy::{AlignItems, Dimension, FlexDirection, LengthPercentage};
+
+use crate::{
+    elements::{
+        node::{GlobalZIndex, Node, TopLayer},
+        text::Text,
+    },
+    interaction::Interaction,
+    screen_space::{ScreenAnchor, ScreenSpace},
+    style::{Corners, Style},
+};
+
+/// A root node which stacks its [`Toast`]s at the `anchor` of the viewport of its camera,
+/// the newest toast is the closest to the anchor.
+///
+/// It's stacked above the other top layer nodes, e.g. the modals, and its camera is chosen by
+/// its [`ScreenSpace`].
+#[derive(Component, Clone, Copy, Debug, PartialEq)]
+#[require(Node, ScreenSpace, TopLayer, GlobalZIndex(1), Pickable::IGNORE)]
+pub struct ToastStack {
+    pub anchor: ScreenAnchor,
+    /// The number of shown toasts, the oldest ones are dismissed first.
+    pub limit: usize,
+}
+
+impl Default for ToastStack {
+    fn default() -> Self {
+        Self::new(ScreenAnchor::BottomRight)
+    }
+}
+
+impl ToastStack {
+    #[inline]
+    pub const fn new(anchor: ScreenAnchor) -> Self {
+        Self { anchor, limit: 5 }
+    }
+
+    #[inline]
+    pub const fn limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+}
+
+/// A notification with a text, dismissed after its duration.
+///
+/// The time is paused while it's hovered.
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(Node, Interaction, AccessibilityNode = toast_accessible_node())]
+pub struct Toast {
+    pub text: String,
+    pub duration: Duration,
+    elapsed: Duration,
+}
+
+impl Toast {
+    #[inline]
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            duration: Duration::from_secs(4),
+            elapsed: Duration::ZERO,
+        }
+    }
+
+    #[inline]
+    pub const fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+}
+
+fn toast_accessible_node() -> AccessibilityNode {
+    let mut node = accesskit::Node::new(Role::Status);
+    node.set_live(Live::Polite);
+    AccessibilityNode::from(node)
+}
+
+/// Triggered on a [`Toast`] before it's despawned, after its duration or with a click.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct ToastDismiss {
+    pub entity: Entity,
+}
+
+/// The look of the toasts.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct ToastStyle {
+    pub background: Color,
+    pub text_color: Color,
+    pub font_size: f32,
+    pub padding: f32,
+    pub corner_radius: f32,
+    pub max_width: f32,
+    /// The distance between the toasts.
+    pub gap: f32,
+    /// The distance between the stack and the edges of the viewport.
+    pub margin: f32,
+}
+
+impl Default for ToastStyle {
+    fn default() -> Self {
+        Self {
+            background: Color::srgb(0.16, 0.16, 0.18),
+            text_color: Color::srgb(0.92, 0.92, 0.92),
+            font_size: 14.0,
+            padding: 12.0,
+            corner_radius: 6.0,
+            max_width: 320.0,
+            gap: 8.0,
+            margin: 16.0,
+        }
+    }
+}
+
+/// A plugin that adds [`Toast`]s.
+pub struct ToastPlugin;
+
+impl Plugin for ToastPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ToastStyle>()
+            .add_observer(on_add_toast_stack)
+            .add_observer(on_add_toast)
+            .add_observer(on_toast_click)
+            .add_systems(Update, toast_system);
+    }
+}
+
+fn on_add_toast_stack(
+    event: On<Add, ToastStack>,
+    toast_style: Res<ToastStyle>,
+    mut stack_query: Query<(&ToastStack, &mut ScreenSpace, &mut Style)>,
+) {
+    let Ok((stack, mut screen_space, mut style)) = stack_query.get_mut(event.entity) else {
+        return;
+    };
+
+    // Moves the stack away from the edges of the viewport.
+    let margin = toast_style.margin;
+    screen_space.anchor = stack.anchor;
+    screen_space.offset = Vec2::new(
+        if stack.anchor.is_left() {
+            margin
+        } else if stack.anchor.is_right() {
+            -margin
+        } else {
+            0.0
+        },
+        if stack.anchor.is_top() {
+            margin
+        } else if stack.anchor.is_bottom() {
+            -margin
+        } else {
+            0.0
+        },
+    );
+
+    let inner = style.get_mut();
+    // The newest toast, the last child, is the closest to the anchor.
+    inner.flex_direction = if stack.anchor.is_top() {
+        FlexDirection::ColumnReverse
+    } else {
+        FlexDirection::Column
+    };
+    inner.align_items = Some(if stack.anchor.is_left() {
+        AlignItems::FlexStart
+    } else if stack.anchor.is_right() {
+        AlignItems::FlexEnd
+    } else {
+        AlignItems::Center
+    });
+    inner.gap = taffy::Size {
+        width: LengthPercentage::length(0.0),
+        height: LengthPercentage::length(toast_style.gap),
+    };
+}
+
+fn on_add_toast(
+    event: On<Add, Toast>,
+    toast_style: Res<ToastStyle>,
+    mut toast_query: Query<(&Toast, &mut Style, &mut AccessibilityNode)>,
+    mut commands: Commands,
+) {
+    let Ok((toast, mut style, mut accessible)) = toast_query.get_mut(event.entity) else {
+        return;
+    };
+
+    *style = Style::from(taffy::Style {
+        max_size: taffy::Size {
+            width: Dimension::length(toast_style.max_width),
+            height: Dimension::auto(),
+        },
+        padding: taffy::Rect {
+            left: LengthPercentage::length(toast_style.padding),
+            right: LengthPercentage::length(toast_style.padding),
+            top: LengthPercentage::length(0.75 * toast_style.padding),
+            bottom: LengthPercentage::length(0.75 * toast_style.padding),
+        },
+        ..Default::default()
+    })
+    .background(toast_style.background)
+    .corner_radii(Corners::all(toast_style.corner_radius));
+
+    accessible.set_label(toast.text.as_str());
+
+    commands.spawn((
+        Text::new(toast.text.as_str()),
+        TextFont::from_font_size(toast_style.font_size),
+        TextColor(toast_style.text_color),
+        Pickable::IGNORE,
+        ChildOf(event.entity),
+    ));
+}
+
+fn dismiss(commands: &mut Commands, entity: Entity) {
+    commands.trigger(ToastDismiss { entity });
+    commands.entity(entity).try_despawn();
+}
+
+fn on_toast_click(
+    mut event: On<Pointer<Click>>,
+    toast_query: Query<(), With<Toast>>,
+    mut commands: Commands,
+) {
+    if !toast_query.contains(event.entity) || event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    dismiss(&mut commands, event.entity);
+}
+
+/// Dismisses the expired toasts, and the oldest toasts over the limit of their stacks.
+pub fn toast_system(
+    time: Res<Time>,
+    stack_query: Query<(&ToastStack, &Children)>,
+    mut toast_query: Query<(&mut Toast, &Interaction)>,
+    mut commands: Commands,
+) {
+    for (stack, children) in stack_query.iter() {
+        let count = toast_query.iter_many(children).count();
+        let mut excess = count.saturating_sub(stack.limit);
+
+        // The children are ordered from the oldest toast.
+        for &child in children {
+            let Ok((mut toast, interaction)) = toast_query.get_mut(child) else {
+                continue;
+            };
+
+            if excess > 0 {
+                excess -= 1;
+            } else if interaction.hovered {
+                continue;
+            } else {
+                toast.elapsed += time.delta();
+                if toast.elapsed < toast.duration {
+                    continue;
+                }
+            }
+
+            dismiss(&mut commands, child);
+        }
+    }
+}
@@ -0,0 +1,887 @@
+//! Tree views, for outliners of scene hierarchies and node groups.
+//!
+//! A [`TreeView`] holds the loaded items, and shows the rows of the expanded ones in its
+//! [`VirtualList`], so only the rows in the viewport are spawned for large trees.
+
+use accesskit::Role;
+use bevy_a11y::AccessibilityNode;
+use bevy_app::{AnimationSystems, App, Plugin, PostUpdate};
+use bevy_color::Color;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    event::EntityEvent,
+    hierarchy::ChildOf,
+    observer::On,
+    query::{Changed, Has},
+    resource::Resource,
+    schedule::IntoScheduleConfigs,
+    system::{Commands, Query, Res, ResMut},
+};
+use bevy_input::{
+    ButtonInput, ButtonState,
+    keyboard::{KeyCode, KeyboardInput},
+};
+use bevy_input_focus::{FocusedInput, InputFocus, tab_navigation::TabIndex};
+use bevy_picking::{
+    Pickable,
+    events::{Click, Pointer},
+    pointer::PointerButton,
+};
+use bevy_platform::collections::HashMap;
+use bevy_text::{TextColor, TextFont};
+use taffy::{AlignItems, Dimension, FlexDirection, LengthPercentage};
+
+use crate::{
+    computed::ComputedNode,
+    elements::{node::ScrollPosition, text::Text},
+    focus::CaptureArrowKeys,
+    interaction::{Checked, Interaction},
+    states::{StyleStates, StyleVariant},
+    style::{Corners, Style},
+    virtualize::{RowHeight, VirtualItem, VirtualItemBind, VirtualList, virtual_list_system},
+};
+
+use super::ValueChange;
+
+/// An item of a [`TreeView`].
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct TreeItem {
+    pub id: u64,
+    pub label: String,
+    /// Shows the expand toggle, the children may be loaded when it's expanded.
+    pub has_children: bool,
+}
+
+impl TreeItem {
+    #[inline]
+    pub fn new(id: u64, label: impl Into<String>) -> Self {
+        Self {
+            id,
+            label: label.into(),
+            has_children: false,
+        }
+    }
+
+    #[inline]
+    pub const fn has_children(mut self, has_children: bool) -> Self {
+        self.has_children = has_children;
+        self
+    }
+}
+
+/// A shown row of a [`TreeView`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct TreeRowInfo {
+    pub id: u64,
+    /// The number of ancestors of the item.
+    pub depth: usize,
+}
+
+/// How a click or a key changes the selection of a [`TreeView`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TreeSelect {
+    /// Selects only the item.
+    #[default]
+    Single,
+    /// Selects the shown rows from the anchor to the item, with `Shift`.
+    Range,
+    /// Adds or removes the item, with `Ctrl` or `Cmd`.
+    Toggle,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+struct TreeEntry {
+    item: TreeItem,
+    parent: Option<u64>,
+    /// The children of the item, `None` until they're loaded.
+    children: Option<Vec<u64>>,
+    expanded: bool,
+}
+
+/// A tree view, the rows of its expanded items are shown in its [`VirtualList`].
+///
+/// Clicking the toggle of a row or pressing `Right` and `Left` expands and collapses it,
+/// triggers a [`TreeExpand`], and a [`TreeLoadChildren`] if its children are not loaded yet.
+/// Clicking a row selects it, with `Shift` for a range and `Ctrl` or `Cmd` to toggle it,
+/// the arrow keys, `Home` and `End` move the selection. Triggers a [`ValueChange<Vec<u64>>`]
+/// with the selected ids when the selection is changed by the user.
+#[derive(Component, Clone, Debug, PartialEq)]
+#[require(
+    VirtualList = VirtualList::fixed(0, 22.0).overscan(8),
+    Interaction,
+    TabIndex,
+    CaptureArrowKeys,
+    AccessibilityNode = AccessibilityNode::from(accesskit::Node::new(Role::Tree))
+)]
+pub struct TreeView {
+    entries: HashMap<u64, TreeEntry>,
+    roots: Vec<u64>,
+    rows: Vec<TreeRowInfo>,
+    /// The index of the row of each shown item.
+    row_indices: HashMap<u64, usize>,
+    selection: Vec<u64>,
+    anchor: Option<u64>,
+    cursor: Option<u64>,
+    /// Allows the range and toggle selections.
+    pub multi_select: bool,
+}
+
+impl Default for TreeView {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TreeView {
+    #[inline]
+    pub fn new() -> Self {
+        Self {
+            entries: HashMap::default(),
+            roots: Vec::new(),
+            rows: Vec::new(),
+            row_indices: HashMap::default(),
+            selection: Vec::new(),
+            anchor: None,
+            cursor: None,
+            multi_select: true,
+        }
+    }
+
+    #[inline]
+    pub const fn multi_select(mut self, multi_select: bool) -> Self {
+        self.multi_select = multi_select;
+        self
+    }
+
+    /// Adds the roots to the tree.
+    pub fn with_roots(mut self, items: impl IntoIterator<Item = TreeItem>) -> Self {
+        for item in items {
+            self.insert_entry(None, item);
+        }
+        self.rebuild_rows();
+        self
+    }
+
+    /// Adds an item as the last child of the parent, or as a root.
+    ///
+    /// An existing item with the id is updated in place.
+    pub fn insert(&mut self, parent: Option<u64>, item: TreeItem) {
+        self.insert_entry(parent, item);
+        self.rebuild_rows();
+    }
+
+    /// Adds or updates an item, without rebuilding the rows.
+    fn insert_entry(&mut self, parent: Option<u64>, item: TreeItem) {
+        if let Some(entry) = self.entries.get_mut(&item.id) {
+            entry.item = item;
+            return;
+        }
+
+        let siblings = match parent {
+            Some(parent) => {
+                let Some(entry) = self.entries.get_mut(&parent) else {
+                    return;
+                };
+                entry.item.has_children = true;
+                entry.children.get_or_insert_with(Vec::new)
+            }
+            None => &mut self.roots,
+        };
+        siblings.push(item.id);
+
+        self.entries.insert(
+            item.id,
+            TreeEntry {
+                item,
+                parent,
+                children: None,
+                expanded: false,
+            },
+        );
+    }
+
+    /// Replaces the children of an item, e.g. when they're loaded.
+    pub fn set_children(&mut self, parent: u64, items: impl IntoIterator<Item = TreeItem>) {
+        let Some(entry) = self.entries.get_mut(&parent) else {
+            return;
+        };
+
+        let old = entry.children.replace(Vec::new()).unwrap_or_default();
+        for id in old {
+            self.remove_entry(id);
+        }
+
+        for item in items {
+            self.insert_entry(Some(parent), item);
+        }
+
+        if let Some(entry) = self.entries.get_mut(&parent) {
+            entry.item.has_children = entry
+                .children
+                .as_ref()
+                .is_some_and(|children| !children.is_empty());
+        }
+        self.rebuild_rows();
+    }
+
+    /// Removes an item and its descendants.
+    pub fn remove(&mut self, id: u64) {
+        let Some(entry) = self.entries.get(&id) else {
+            return;
+        };
+
+        match entry
+            .parent
+            .and_then(|parent| self.entries.get_mut(&parent))
+        {
+            Some(parent) => {
+                if let Some(children) = &mut parent.children {
+                    children.retain(|child| *child != id);
+                }
+            }
+            None => self.roots.retain(|root| *root != id),
+        }
+
+        self.remove_entry(id);
+        self.rebuild_rows();
+    }
+
+    fn remove_entry(&mut self, id: u64) {
+        let Some(entry) = self.entries.remove(&id) else {
+            return;
+        };
+
+        self.selection.retain(|selected| *selected != id);
+        if self.anchor == Some(id) {
+            self.anchor = None;
+        }
+        if self.cursor == Some(id) {
+            self.cursor = None;
+        }
+
+        for child in entry.children.into_iter().flatten() {
+            self.remove_entry(child);
+        }
+    }
+
+    #[inline]
+    pub fn get(&self, id: u64) -> Option<&TreeItem> {
+        self.entries.get(&id).map(|entry| &entry.item)
+    }
+
+    #[inline]
+    pub fn parent(&self, id: u64) -> Option<u64> {
+        self.entries.get(&id).and_then(|entry| entry.parent)
+    }
+
+    /// Returns the children of an item, `None` if they're not loaded.
+    #[inline]
+    pub fn children(&self, id: u64) -> Option<&[u64]> {
+        self.entries
+            .get(&id)
+            .and_then(|entry| entry.children.as_deref())
+    }
+
+    #[inline]
+    pub fn roots(&self) -> &[u64] {
+        &self.roots
+    }
+
+    /// Returns `true` if the item has children which are not loaded.
+    #[inline]
+    pub fn needs_children(&self, id: u64) -> bool {
+        self.entries
+            .get(&id)
+            .is_some_and(|entry| entry.item.has_children && entry.children.is_none())
+    }
+
+    #[inline]
+    pub fn is_expanded(&self, id: u64) -> bool {
+        self.entries.get(&id).is_some_and(|entry| entry.expanded)
+    }
+
+    /// Expands or collapses an item, returns `true` if it's changed.
+    pub fn set_expanded(&mut self, id: u64, expanded: bool) -> bool {
+        let Some(entry) = self
+            .entries
+            .get_mut(&id)
+            .filter(|entry| entry.item.has_children && entry.expanded != expanded)
+        else {
+            return false;
+        };
+
+        entry.expanded = expanded;
+        self.rebuild_rows();
+        true
+    }
+
+    /// Expands the ancestors of an item, so its row is shown.
+    pub fn reveal(&mut self, id: u64) {
+        let mut parent = self.parent(id);
+        while let Some(id) = parent {
+            if let Some(entry) = self.entries.get_mut(&id) {
+                entry.expanded = true;
+            }
+            parent = self.parent(id);
+        }
+        self.rebuild_rows();
+    }
+
+    /// Returns the shown rows, the items whose ancestors are expanded.
+    #[inline]
+    pub fn rows(&self) -> &[TreeRowInfo] {
+        &self.rows
+    }
+
+    /// Returns the index of the row of an item, if it's shown.
+    #[inline]
+    pub fn row_index(&self, id: u64) -> Option<usize> {
+        self.row_indices.get(&id).copied()
+    }
+
+    /// Returns the selected ids, in the order they were selected.
+    #[inline]
+    pub fn selection(&self) -> &[u64] {
+        &self.selection
+    }
+
+    #[inline]
+    pub fn is_selected(&self, id: u64) -> bool {
+        self.selection.contains(&id)
+    }
+
+    /// Returns the item moved by the keys, the last clicked or selected one.
+    #[inline]
+    pub fn cursor(&self) -> Option<u64> {
+        self.cursor
+    }
+
+    /// Selects an item, the range and toggle modes need [`TreeView::multi_select`].
+    pub fn select(&mut self, id: u64, mode: TreeSelect) {
+        if !self.entries.contains_key(&id) {
+            return;
+        }
+
+        let mode = if self.multi_select {
+            mode
+        } else {
+            TreeSelect::Single
+        };
+
+        match mode {
+            TreeSelect::Single => {
+                self.selection.clear();
+                self.selection.push(id);
+                self.anchor = Some(id);
+            }
+            TreeSelect::Toggle => {
+                if let Some(index) = self.selection.iter().position(|selected| *selected == id) {
+                    self.selection.remove(index);
+                } else {
+                    self.selection.push(id);
+                }
+                self.anchor = Some(id);
+            }
+            TreeSelect::Range => {
+                let anchor = self.anchor.and_then(|anchor| self.row_index(anchor));
+                match (anchor, self.row_index(id)) {
+                    (Some(anchor), Some(index)) => {
+                        let range = anchor.min(index)..=anchor.max(index);
+                        self.selection = self.rows[range].iter().map(|row| row.id).collect();
+                    }
+                    _ => {
+                        self.selection.clear();
+                        self.selection.push(id);
+                        self.anchor = Some(id);
+                    }
+                }
+            }
+        }
+
+        self.cursor = Some(id);
+    }
+
+    #[inline]
+    pub fn clear_selection(&mut self) {
+        self.selection.clear();
+        self.anchor = None;
+    }
+
+    fn rebuild_rows(&mut self) {
+        fn push(tree: &TreeView, rows: &mut Vec<TreeRowInfo>, id: u64, depth: usize) {
+            let Some(entry) = tree.entries.get(&id) else {
+                return;
+            };
+
+            rows.push(TreeRowInfo { id, depth });
+
+            if entry.expanded {
+                for &child in entry.children.iter().flatten() {
+                    push(tree, rows, child, depth + 1);
+                }
+            }
+        }
+
+        let mut rows = core::mem::take(&mut self.rows);
+        rows.clear();
+        for &root in &self.roots {
+            push(self, &mut rows, root, 0);
+        }
+        self.rows = rows;
+
+        self.row_indices.clear();
+        self.row_indices.extend(
+            self.rows
+                .iter()
+                .enumerate()
+                .map(|(index, row)| (row.id, index)),
+        );
+    }
+}
+
+/// Triggered on a [`TreeView`] when an item is expanded or collapsed by the user.
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct TreeExpand {
+    pub entity: Entity,
+    pub id: u64,
+    pub expanded: bool,
+}
+
+/// Triggered on a [`TreeView`] when an item is expanded before its children are loaded.
+///
+/// Load them with [`TreeView::set_children`].
+#[derive(EntityEvent, Clone, Copy, Debug)]
+pub struct TreeLoadChildren {
+    pub entity: Entity,
+    pub id: u64,
+}
+
+/// A row of a [`TreeView`], a recycled [`VirtualItem`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TreeRow {
+    pub tree: Entity,
+    toggle: Entity,
+    label: Entity,
+}
+
+/// The expand toggle of a [`TreeRow`].
+#[derive(Component, Clone, Copy, Debug)]
+pub struct TreeToggle {
+    pub row: Entity,
+}
+
+/// The look of the tree views.
+#[derive(Resource, Clone, Copy, Debug)]
+pub struct TreeStyle {
+    /// The indentation of a level, also the width of the toggles.
+    pub indent: f32,
+    pub padding: f32,
+    pub text_color: Color,
+    pub toggle_color: Color,
+    pub hovered_background: Color,
+    pub selected_background: Color,
+    pub font_size: f32,
+    pub corner_radius: f32,
+}
+
+impl Default for TreeStyle {
+    fn default() -> Self {
+        Self {
+            indent: 16.0,
+            padding: 4.0,
+            text_color: Color::srgb(0.92, 0.92, 0.92),
+            toggle_color: Color::srgb(0.6, 0.6, 0.62),
+            hovered_background: Color::srgb(0.19, 0.19, 0.22),
+            selected_background: Color::srgb(0.24, 0.42, 0.8),
+            font_size: 13.0,
+            corner_radius: 3.0,
+        }
+    }
+}
+
+/// A plugin that adds [`TreeView`]s.
+pub struct TreePlugin;
+
+impl Plugin for TreePlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<TreeStyle>()
+            .add_observer(on_tree_row_bind)
+            .add_observer(on_tree_toggle_click)
+            .add_observer(on_tree_row_click)
+            .add_observer(on_tree_key)
+            .add_systems(
+                PostUpdate,
+                tree_view_system
+                    .before(virtual_list_system)
+                    .before(AnimationSystems),
+            );
+    }
+}
+
+/// Expands or collapses an item, triggers the [`TreeExpand`] and [`TreeLoadChildren`] events.
+fn expand(commands: &mut Commands, entity: Entity, tree: &mut TreeView, id: u64, expanded: bool) {
+    if !tree.set_expanded(id, expanded) {
+        return;
+    }
+
+    commands.trigger(TreeExpand {
+        entity,
+        id,
+        expanded,
+    });
+
+    if expanded && tree.needs_children(id) {
+        commands.trigger(TreeLoadChildren { entity, id });
+    }
+}
+
+/// Triggers a [`ValueChange<Vec<u64>>`] if the selection is changed.
+fn selection_changed(commands: &mut Commands, entity: Entity, tree: &TreeView, before: &[u64]) {
+    if tree.selection() != before {
+        commands.trigger(ValueChange {
+            entity,
+            value: tree.selection().to_vec(),
+        });
+    }
+}
+
+/// Shows the rows of the changed trees in their lists.
+pub fn tree_view_system(
+    mut tree_query: Query<
+        (&TreeView, &mut VirtualList, Option<&mut AccessibilityNode>),
+        Changed<TreeView>,
+    >,
+) {
+    for (tree, mut list, accessible) in tree_query.iter_mut() {
+        // Binds the shown rows again, even if the number of rows is the same.
+        list.len = tree.rows().len();
+
+        if let Some(mut accessible) = accessible {
+            if tree.multi_select {
+                accessible.set_multiselectable();
+            } else {
+                accessible.clear_multiselectable();
+            }
+        }
+    }
+}
+
+type TreeRowData = (
+    Option<&'static TreeRow>,
+    Has<Checked>,
+    &'static mut Style,
+    Option<&'static mut AccessibilityNode>,
+);
+
+fn on_tree_row_bind(
+    event: On<VirtualItemBind>,
+    style: Res<TreeStyle>,
+    tree_query: Query<&TreeView>,
+    mut row_query: Query<TreeRowData>,
+    mut text_query: Query<&mut Text>,
+    mut commands: Commands,
+) {
+    let Ok(tree) = tree_query.get(event.list) else {
+        return;
+    };
+    let Some(row) = tree.rows().get(event.index) else {
+        return;
+    };
+    let Some(item) = tree.get(row.id) else {
+        return;
+    };
+    let Ok((tree_row, checked, mut row_style, accessible)) = row_query.get_mut(event.entity) else {
+        return;
+    };
+
+    let toggle_text = match (item.has_children, tree.is_expanded(row.id)) {
+        (false, _) => "",
+        (true, false) => "▸",
+        (true, true) => "▾",
+    };
+
+    match tree_row {
+        Some(tree_row) => {
+            for (entity, value) in [
+                (tree_row.toggle, toggle_text),
+                (tree_row.label, &item.label),
+            ] {
+                if let Ok(mut text) = text_query.get_mut(entity)
+                    && text.0 != value
+                {
+                    text.0 = value.to_owned();
+                }
+            }
+        }
+        None => {
+            let toggle = commands
+                .spawn((
+                    TreeToggle { row: event.entity },
+                    Text::new(toggle_text),
+                    TextFont::from_font_size(style.font_size),
+                    TextColor(style.toggle_color),
+                    ChildOf(event.entity),
+                    Style::from(taffy::Style {
+                        flex_shrink: 0.0,
+                        size: taffy::Size {
+                            width: Dimension::length(style.indent),
+                            height: Dimension::auto(),
+                        },
+                        ..Default::default()
+                    }),
+                ))
+                .id();
+            let label = commands
+                .spawn((
+                    Text::new(item.label.as_str()),
+                    TextFont::from_font_size(style.font_size),
+                    TextColor(style.text_color),
+                    Pickable::IGNORE,
+                    ChildOf(event.entity),
+                ))
+                .id();
+
+            commands.entity(event.entity).insert((
+                TreeRow {
+                    tree: event.list,
+                    toggle,
+                    label,
+                },
+                StyleStates::new()
+                    .hovered(StyleVariant::new().background(style.hovered_background))
+                    .checked(StyleVariant::new().background(style.selected_background)),
+            ));
+
+            row_style.corner_radii = Corners::all(style.corner_radius);
+            let inner = row_style.get_mut();
+            inner.flex_direction = FlexDirection::Row;
+            inner.align_items = Some(AlignItems::Center);
+        }
+    }
+
+    let padding = LengthPercentage::length(style.padding + row.depth as f32 * style.indent);
+    if row_style.get_ref().padding.left != padding {
+        row_style.get_mut().padding.left = padding;
+    }
+
+    let selected = tree.is_selected(row.id);
+    if selected && !checked {
+        commands.entity(event.entity).insert(Checked);
+    } else if !selected && checked {
+        commands.entity(event.entity).remove::<Checked>();
+    }
+
+    let mut node = accesskit::Node::new(Role::TreeItem);
+    node.set_label(item.label.as_str());
+    node.set_level(row.depth + 1);
+    node.set_selected(selected);
+    if item.has_children {
+        node.set_expanded(tree.is_expanded(row.id));
+    }
+    match accessible {
+        Some(mut accessible) => *accessible = AccessibilityNode::from(node),
+        None => {
+            commands
+                .entity(event.entity)
+                .insert(AccessibilityNode::from(node));
+        }
+    }
+}
+
+fn on_tree_toggle_click(
+    mut event: On<Pointer<Click>>,
+    toggle_query: Query<&TreeToggle>,
+    row_query: Query<(&TreeRow, &VirtualItem)>,
+    mut tree_query: Query<(&mut TreeView, &Interaction)>,
+    mut commands: Commands,
+) {
+    let Ok(toggle) = toggle_query.get(event.entity) else {
+        return;
+    };
+    let Ok((row, item)) = row_query.get(toggle.row) else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    let Ok((mut tree, interaction)) = tree_query.get_mut(row.tree) else {
+        return;
+    };
+    let Some(id) = tree.rows().get(item.index).map(|row| row.id) else {
+        return;
+    };
+    if interaction.disabled || !tree.get(id).is_some_and(|item| item.has_children) {
+        return;
+    }
+
+    event.propagate(false);
+
+    let expanded = !tree.is_expanded(id);
+    expand(&mut commands, row.tree, &mut tree, id, expanded);
+}
+
+fn on_tree_row_click(
+    mut event: On<Pointer<Click>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    row_query: Query<(&TreeRow, &VirtualItem)>,
+    mut tree_query: Query<(&mut TreeView, &Interaction)>,
+    mut input_focus: ResMut<InputFocus>,
+    mut commands: Commands,
+) {
+    let Ok((row, item)) = row_query.get(event.entity) else {
+        return;
+    };
+    if event.button != PointerButton::Primary {
+        return;
+    }
+
+    event.propagate(false);
+
+    let Ok((mut tree, interaction)) = tree_query.get_mut(row.tree) else {
+        return;
+    };
+    let Some(id) = tree.rows().get(item.index).map(|row| row.id) else {
+        return;
+    };
+    if interaction.disabled {
+        return;
+    }
+
+    input_focus.set(row.tree);
+
+    let mode = if keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]) {
+        TreeSelect::Range
+    } else if keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]) {
+        TreeSelect::Toggle
+    } else {
+        TreeSelect::Single
+    };
+
+    let before = tree.selection().to_vec();
+    tree.select(id, mode);
+    selection_changed(&mut commands, row.tree, &tree, &before);
+}
+
+fn on_tree_key(
+    mut event: On<FocusedInput<KeyboardInput>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    mut tree_query: Query<(
+        &mut TreeView,
+        &Interaction,
+        &VirtualList,
+        &mut ScrollPosition,
+        &ComputedNode,
+    )>,
+    mut commands: Commands,
+) {
+    let entity = event.focused_entity;
+    let Ok((mut tree, interaction, list, mut scroll_position, computed_node)) =
+        tree_query.get_mut(entity)
+    else {
+        return;
+    };
+    if event.input.state != ButtonState::Pressed || interaction.disabled {
+        return;
+    }
+
+    let rows = tree.rows().len();
+    if rows == 0 {
+        return;
+    }
+
+    let cursor = tree.cursor().and_then(|id| tree.row_index(id));
+    let shift = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let control = keys.any_pressed([
+        KeyCode::ControlLeft,
+        KeyCode::ControlRight,
+        KeyCode::SuperLeft,
+        KeyCode::SuperRight,
+    ]);
+
+    let target = match event.input.key_code {
+        KeyCode::ArrowDown => cursor.map_or(0, |index| (index + 1).min(rows - 1)),
+        KeyCode::ArrowUp => cursor.map_or(0, |index| index.saturating_sub(1)),
+        KeyCode::Home => 0,
+        KeyCode::End => rows - 1,
+        KeyCode::ArrowRight | KeyCode::ArrowLeft => {
+            let Some(index) = cursor else {
+                return;
+            };
+            let id = tree.rows()[index].id;
+            let right = event.input.key_code == KeyCode::ArrowRight;
+
+            event.propagate(false);
+
+            if right != tree.is_expanded(id) && tree.get(id).is_some_and(|item| item.has_children) {
+                expand(&mut commands, entity, &mut tree, id, right);
+                return;
+            }
+
+            // Moves to the first child, or to the parent.
+            let next = if right {
+                tree.children(id)
+                    .and_then(|children| children.first().copied())
+            } else {
+                tree.parent(id)
+            };
+            match next.and_then(|id| tree.row_index(id)) {
+                Some(index) => index,
+                None => return,
+            }
+        }
+        KeyCode::Space => {
+            let Some(id) = tree.cursor() else {
+                return;
+            };
+
+            event.propagate(false);
+
+            let before = tree.selection().to_vec();
+            let mode = if control {
+                TreeSelect::Toggle
+            } else {
+                TreeSelect::Single
+            };
+            tree.select(id, mode);
+            selection_changed(&mut commands, entity, &tree, &before);
+            return;
+        }
+        _ => return,
+    };
+
+    event.propagate(false);
+
+    let id = tree.rows()[target].id;
+    let before = tree.selection().to_vec();
+    match (shift, control) {
+        (true, _) => tree.select(id, TreeSelect::Range),
+        // Moves the cursor without changing the selection.
+        (false, true) => tree.cursor = Some(id),
+        (false, false) => tree.select(id, TreeSelect::Single),
+    }
+    selection_changed(&mut commands, entity, &tree, &before);
+
+    // Scrolls the row into the viewport.
+    let height = match list.row_height {
+        RowHeight::Fixed(height) | RowHeight::Measured { estimate: height } => height,
+    };
+    let top = target as f32 * height;
+    let viewport = computed_node.content_box_size.y;
+    let offset = if top < scroll_position.0.y {
+        top
+    } else if top + height > scroll_position.0.y + viewport {
+        top + height - viewport
+    } else {
+        scroll_position.0.y
+    };
+    if offset != scroll_position.0.y {
+        scroll_position.0.y = offset.max(0.0);
+    }
+}
@@ -0,0 +1,117 @@
+//! Zoom invariant nodes, which keep their size on the screen while the camera zooms.
+//!
+//! A [`ZoomInvariant`] node is scaled by the [`ComputedTargetInfo::zoom_factor`] of its camera,
+//! around its center. Its layout, and the layout of its descendants, is unchanged, so it stays at
+//! its place in the layout of its parent or at the world position of a root.
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_camera::Camera;
+use bevy_ecs::{
+    component::Component,
+    entity::Entity,
+    hierarchy::ChildOf,
+    query::With,
+    reflect::ReflectComponent,
+    schedule::IntoScheduleConfigs,
+    system::{Query, Res},
+};
+use bevy_math::Vec3;
+use bevy_reflect::{Reflect, prelude::ReflectDefault};
+use bevy_transform::{TransformSystems, components::Transform};
+
+use crate::{UiSystems, computed::ComputedTargetInfo, elements::node::Node, stack::UiStackMap};
+
+/// Counter-scales a node from the zoom of its camera, to keep its size on the screen,
+/// e.g. the handles, the port dots and the labels of a zoomable canvas.
+///
+/// The scale is clamped between `min_scale` and `max_scale`, so the node still grows or shrinks
+/// past them. A zoom invariant node inside another one is only scaled by the outer one.
+#[derive(Component, Clone, Copy, Debug, PartialEq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+#[require(Node)]
+pub struct ZoomInvariant {
+    pub min_scale: f32,
+    pub max_scale: f32,
+}
+
+impl Default for ZoomInvariant {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ZoomInvariant {
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            min_scale: 0.0,
+            max_scale: f32::INFINITY,
+        }
+    }
+
+    #[inline]
+    pub const fn min_scale(mut self, min_scale: f32) -> Self {
+        self.min_scale = min_scale;
+        self
+    }
+
+    #[inline]
+    pub const fn max_scale(mut self, max_scale: f32) -> Self {
+        self.max_scale = max_scale;
+        self
+    }
+
+    /// Returns the scale of the node for the zoom factor of its camera.
+    #[inline]
+    pub fn scale(&self, zoom_factor: f32) -> f32 {
+        zoom_factor.max(self.min_scale).min(self.max_scale)
+    }
+}
+
+/// A plugin that scales the [`ZoomInvariant`] nodes.
+pub struct ZoomInvariantPlugin;
+
+impl Plugin for ZoomInvariantPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            zoom_invariant_system
+                .after(UiSystems::Layout)
+                .before(TransformSystems::Propagate),
+        );
+    }
+}
+
+pub fn zoom_invariant_system(
+    ui_stack_map: Res<UiStackMap>,
+    camera_query: Query<&ComputedTargetInfo, With<Camera>>,
+    parent_query: Query<&ChildOf>,
+    invariant_query: Query<(), With<ZoomInvariant>>,
+    mut node_query: Query<(Entity, &ZoomInvariant, &mut Transform)>,
+) {
+    for (entity, zoom_invariant, mut transform) in node_query.iter_mut() {
+        if parent_query
+            .iter_ancestors(entity)
+            .any(|ancestor| invariant_query.contains(ancestor))
+        {
+            continue;
+        }
+
+        let Some(target_info) = ui_stack_map
+            .find_camera(entity)
+            .and_then(|camera_entity| camera_query.get(camera_entity).ok())
+        else {
+            continue;
+        };
+
+        let scale = zoom_invariant.scale(target_info.zoom_factor);
+        if !scale.is_finite() || scale <= 0.0 {
+            continue;
+        }
+
+        let scale = Vec3::new(scale, scale, 1.0);
+        if transform.scale != scale {
+            transform.scale = scale;
+        }
+    }
+}